@@ -54,12 +54,206 @@ resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodes_hydra_do
                       "nullable" = true
                       "type"     = "boolean"
                     }
+                    "cardanoKeys" = {
+                      "description" = "Which Cardano key the node signs with."
+                      "enum" = [
+                        "Shared",
+                        "PerNode",
+                      ]
+                      "nullable" = true
+                      "type"     = "string"
+                    }
                     "commitInputs" = {
                       "items" = {
                         "type" = "string"
                       }
                       "type" = "array"
                     }
+                    "containerResources" = {
+                      "description" = "Resources for the containers next to `main`, which is configured through `spec.resources`."
+                      "nullable"    = true
+                      "properties" = {
+                        "dmtrctl" = {
+                          "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                          "nullable"    = true
+                          "properties" = {
+                            "limits" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "preset" = {
+                              "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                              "enum" = [
+                                "Small",
+                                "Medium",
+                                "Large",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "requests" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "openHead" = {
+                          "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                          "nullable"    = true
+                          "properties" = {
+                            "limits" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "preset" = {
+                              "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                              "enum" = [
+                                "Small",
+                                "Medium",
+                                "Large",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "requests" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "sidecar" = {
+                          "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                          "nullable"    = true
+                          "properties" = {
+                            "limits" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "preset" = {
+                              "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                              "enum" = [
+                                "Small",
+                                "Medium",
+                                "Large",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "requests" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                      }
+                      "type" = "object"
+                    }
+                    "head" = {
+                      "description" = "Nodes sharing the same head id are allowed to reach each other's peer port when network policies are enabled."
+                      "nullable"    = true
+                      "type"        = "string"
+                    }
+                    "hydraKeySecret" = {
+                      "description" = "Existing secret with `hydra.sk` and `hydra.vk` to use instead of a key pair generated by the operator. Label it `hydra.doom/roll-nodes: \"true\"` to restart the node when it changes."
+                      "nullable"    = true
+                      "type"        = "string"
+                    }
+                    "idlePolicy" = {
+                      "description" = "Opt-in policy that lets the operator put a node to sleep once its transaction counter has not moved for `idle_seconds`. Only nodes that are Online are considered; open heads and every other state are exempt."
+                      "nullable"    = true
+                      "properties" = {
+                        "idleSeconds" = {
+                          "format"  = "uint64"
+                          "minimum" = 0
+                          "type"    = "integer"
+                        }
+                      }
+                      "required" = [
+                        "idleSeconds",
+                      ]
+                      "type" = "object"
+                    }
                     "networkId" = {
                       "format"   = "uint8"
                       "minimum"  = 0
@@ -70,44 +264,1838 @@ resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodes_hydra_do
                       "nullable" = true
                       "type"     = "boolean"
                     }
-                    "resources" = {
+                    "overrides" = {
+                      "description" = "Changes to the generated Deployment, Service, Ingress and ConfigMap, applied after everything else."
+                      "nullable"    = true
+                      "properties" = {
+                        "configmap" = {
+                          "nullable" = true
+                          "properties" = {
+                            "jsonPatch" = {
+                              "description" = "RFC 6902 operations applied after `merge`."
+                              "items" = {
+                                "properties" = {
+                                  "from" = {
+                                    "nullable" = true
+                                    "type"     = "string"
+                                  }
+                                  "op" = {
+                                    "enum" = [
+                                      "add",
+                                      "remove",
+                                      "replace",
+                                      "move",
+                                      "copy",
+                                      "test",
+                                    ]
+                                    "type" = "string"
+                                  }
+                                  "path" = {
+                                    "type" = "string"
+                                  }
+                                  "value" = {
+                                    "nullable"                             = true
+                                    "x-kubernetes-preserve-unknown-fields" = true
+                                  }
+                                }
+                                "required" = [
+                                  "op",
+                                  "path",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "merge" = {
+                              "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                              "nullable"                             = true
+                              "type"                                 = "object"
+                              "x-kubernetes-preserve-unknown-fields" = true
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "deployment" = {
+                          "nullable" = true
+                          "properties" = {
+                            "jsonPatch" = {
+                              "description" = "RFC 6902 operations applied after `merge`."
+                              "items" = {
+                                "properties" = {
+                                  "from" = {
+                                    "nullable" = true
+                                    "type"     = "string"
+                                  }
+                                  "op" = {
+                                    "enum" = [
+                                      "add",
+                                      "remove",
+                                      "replace",
+                                      "move",
+                                      "copy",
+                                      "test",
+                                    ]
+                                    "type" = "string"
+                                  }
+                                  "path" = {
+                                    "type" = "string"
+                                  }
+                                  "value" = {
+                                    "nullable"                             = true
+                                    "x-kubernetes-preserve-unknown-fields" = true
+                                  }
+                                }
+                                "required" = [
+                                  "op",
+                                  "path",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "merge" = {
+                              "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                              "nullable"                             = true
+                              "type"                                 = "object"
+                              "x-kubernetes-preserve-unknown-fields" = true
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "ingress" = {
+                          "nullable" = true
+                          "properties" = {
+                            "jsonPatch" = {
+                              "description" = "RFC 6902 operations applied after `merge`."
+                              "items" = {
+                                "properties" = {
+                                  "from" = {
+                                    "nullable" = true
+                                    "type"     = "string"
+                                  }
+                                  "op" = {
+                                    "enum" = [
+                                      "add",
+                                      "remove",
+                                      "replace",
+                                      "move",
+                                      "copy",
+                                      "test",
+                                    ]
+                                    "type" = "string"
+                                  }
+                                  "path" = {
+                                    "type" = "string"
+                                  }
+                                  "value" = {
+                                    "nullable"                             = true
+                                    "x-kubernetes-preserve-unknown-fields" = true
+                                  }
+                                }
+                                "required" = [
+                                  "op",
+                                  "path",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "merge" = {
+                              "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                              "nullable"                             = true
+                              "type"                                 = "object"
+                              "x-kubernetes-preserve-unknown-fields" = true
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "service" = {
+                          "nullable" = true
+                          "properties" = {
+                            "jsonPatch" = {
+                              "description" = "RFC 6902 operations applied after `merge`."
+                              "items" = {
+                                "properties" = {
+                                  "from" = {
+                                    "nullable" = true
+                                    "type"     = "string"
+                                  }
+                                  "op" = {
+                                    "enum" = [
+                                      "add",
+                                      "remove",
+                                      "replace",
+                                      "move",
+                                      "copy",
+                                      "test",
+                                    ]
+                                    "type" = "string"
+                                  }
+                                  "path" = {
+                                    "type" = "string"
+                                  }
+                                  "value" = {
+                                    "nullable"                             = true
+                                    "x-kubernetes-preserve-unknown-fields" = true
+                                  }
+                                }
+                                "required" = [
+                                  "op",
+                                  "path",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "merge" = {
+                              "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                              "nullable"                             = true
+                              "type"                                 = "object"
+                              "x-kubernetes-preserve-unknown-fields" = true
+                            }
+                          }
+                          "type" = "object"
+                        }
+                      }
+                      "type" = "object"
+                    }
+                    "peerExposure" = {
+                      "description" = "How the hydra peer port is published for other parties of the head."
+                      "enum" = [
+                        "None",
+                        "ClusterIP",
+                        "NodePort",
+                        "LoadBalancer",
+                      ]
                       "nullable" = true
+                      "type"     = "string"
+                    }
+                    "probes" = {
+                      "description" = "Probe overrides. A probe given here replaces the generated default as a whole."
+                      "nullable"    = true
+                      "properties" = {
+                        "mainLiveness" = {
+                          "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                          "nullable"    = true
+                          "properties" = {
+                            "exec" = {
+                              "description" = "Exec specifies the action to take."
+                              "properties" = {
+                                "command" = {
+                                  "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                  "items" = {
+                                    "type" = "string"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "failureThreshold" = {
+                              "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "grpc" = {
+                              "description" = "GRPC specifies an action involving a GRPC port."
+                              "properties" = {
+                                "port" = {
+                                  "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "service" = {
+                                  "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "httpGet" = {
+                              "description" = "HTTPGet specifies the http request to perform."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                  "type"        = "string"
+                                }
+                                "httpHeaders" = {
+                                  "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                  "items" = {
+                                    "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                    "properties" = {
+                                      "name" = {
+                                        "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                        "type"        = "string"
+                                      }
+                                      "value" = {
+                                        "description" = "The header field value"
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "name",
+                                      "value",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "path" = {
+                                  "description" = "Path to access on the HTTP server."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                                "scheme" = {
+                                  "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "initialDelaySeconds" = {
+                              "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "periodSeconds" = {
+                              "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "successThreshold" = {
+                              "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "tcpSocket" = {
+                              "description" = "TCPSocket specifies an action involving a TCP port."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "terminationGracePeriodSeconds" = {
+                              "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                              "format"      = "int64"
+                              "type"        = "integer"
+                            }
+                            "timeoutSeconds" = {
+                              "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "mainReadiness" = {
+                          "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                          "nullable"    = true
+                          "properties" = {
+                            "exec" = {
+                              "description" = "Exec specifies the action to take."
+                              "properties" = {
+                                "command" = {
+                                  "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                  "items" = {
+                                    "type" = "string"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "failureThreshold" = {
+                              "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "grpc" = {
+                              "description" = "GRPC specifies an action involving a GRPC port."
+                              "properties" = {
+                                "port" = {
+                                  "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "service" = {
+                                  "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "httpGet" = {
+                              "description" = "HTTPGet specifies the http request to perform."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                  "type"        = "string"
+                                }
+                                "httpHeaders" = {
+                                  "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                  "items" = {
+                                    "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                    "properties" = {
+                                      "name" = {
+                                        "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                        "type"        = "string"
+                                      }
+                                      "value" = {
+                                        "description" = "The header field value"
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "name",
+                                      "value",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "path" = {
+                                  "description" = "Path to access on the HTTP server."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                                "scheme" = {
+                                  "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "initialDelaySeconds" = {
+                              "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "periodSeconds" = {
+                              "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "successThreshold" = {
+                              "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "tcpSocket" = {
+                              "description" = "TCPSocket specifies an action involving a TCP port."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "terminationGracePeriodSeconds" = {
+                              "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                              "format"      = "int64"
+                              "type"        = "integer"
+                            }
+                            "timeoutSeconds" = {
+                              "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "mainStartup" = {
+                          "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                          "nullable"    = true
+                          "properties" = {
+                            "exec" = {
+                              "description" = "Exec specifies the action to take."
+                              "properties" = {
+                                "command" = {
+                                  "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                  "items" = {
+                                    "type" = "string"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "failureThreshold" = {
+                              "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "grpc" = {
+                              "description" = "GRPC specifies an action involving a GRPC port."
+                              "properties" = {
+                                "port" = {
+                                  "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "service" = {
+                                  "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "httpGet" = {
+                              "description" = "HTTPGet specifies the http request to perform."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                  "type"        = "string"
+                                }
+                                "httpHeaders" = {
+                                  "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                  "items" = {
+                                    "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                    "properties" = {
+                                      "name" = {
+                                        "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                        "type"        = "string"
+                                      }
+                                      "value" = {
+                                        "description" = "The header field value"
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "name",
+                                      "value",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "path" = {
+                                  "description" = "Path to access on the HTTP server."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                                "scheme" = {
+                                  "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "initialDelaySeconds" = {
+                              "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "periodSeconds" = {
+                              "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "successThreshold" = {
+                              "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "tcpSocket" = {
+                              "description" = "TCPSocket specifies an action involving a TCP port."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "terminationGracePeriodSeconds" = {
+                              "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                              "format"      = "int64"
+                              "type"        = "integer"
+                            }
+                            "timeoutSeconds" = {
+                              "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "sidecarLiveness" = {
+                          "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                          "nullable"    = true
+                          "properties" = {
+                            "exec" = {
+                              "description" = "Exec specifies the action to take."
+                              "properties" = {
+                                "command" = {
+                                  "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                  "items" = {
+                                    "type" = "string"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "failureThreshold" = {
+                              "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "grpc" = {
+                              "description" = "GRPC specifies an action involving a GRPC port."
+                              "properties" = {
+                                "port" = {
+                                  "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "service" = {
+                                  "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "httpGet" = {
+                              "description" = "HTTPGet specifies the http request to perform."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                  "type"        = "string"
+                                }
+                                "httpHeaders" = {
+                                  "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                  "items" = {
+                                    "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                    "properties" = {
+                                      "name" = {
+                                        "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                        "type"        = "string"
+                                      }
+                                      "value" = {
+                                        "description" = "The header field value"
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "name",
+                                      "value",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "path" = {
+                                  "description" = "Path to access on the HTTP server."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                                "scheme" = {
+                                  "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "initialDelaySeconds" = {
+                              "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "periodSeconds" = {
+                              "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "successThreshold" = {
+                              "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "tcpSocket" = {
+                              "description" = "TCPSocket specifies an action involving a TCP port."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "terminationGracePeriodSeconds" = {
+                              "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                              "format"      = "int64"
+                              "type"        = "integer"
+                            }
+                            "timeoutSeconds" = {
+                              "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "sidecarReadiness" = {
+                          "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                          "nullable"    = true
+                          "properties" = {
+                            "exec" = {
+                              "description" = "Exec specifies the action to take."
+                              "properties" = {
+                                "command" = {
+                                  "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                  "items" = {
+                                    "type" = "string"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "failureThreshold" = {
+                              "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "grpc" = {
+                              "description" = "GRPC specifies an action involving a GRPC port."
+                              "properties" = {
+                                "port" = {
+                                  "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "service" = {
+                                  "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "httpGet" = {
+                              "description" = "HTTPGet specifies the http request to perform."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                  "type"        = "string"
+                                }
+                                "httpHeaders" = {
+                                  "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                  "items" = {
+                                    "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                    "properties" = {
+                                      "name" = {
+                                        "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                        "type"        = "string"
+                                      }
+                                      "value" = {
+                                        "description" = "The header field value"
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "name",
+                                      "value",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "path" = {
+                                  "description" = "Path to access on the HTTP server."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                                "scheme" = {
+                                  "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                  "type"        = "string"
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "initialDelaySeconds" = {
+                              "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "periodSeconds" = {
+                              "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "successThreshold" = {
+                              "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                            "tcpSocket" = {
+                              "description" = "TCPSocket specifies an action involving a TCP port."
+                              "properties" = {
+                                "host" = {
+                                  "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                  "type"        = "string"
+                                }
+                                "port" = {
+                                  "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                  "x-kubernetes-int-or-string" = true
+                                }
+                              }
+                              "required" = [
+                                "port",
+                              ]
+                              "type" = "object"
+                            }
+                            "terminationGracePeriodSeconds" = {
+                              "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                              "format"      = "int64"
+                              "type"        = "integer"
+                            }
+                            "timeoutSeconds" = {
+                              "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                              "format"      = "int32"
+                              "type"        = "integer"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                      }
+                      "type" = "object"
+                    }
+                    "resources" = {
+                      "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                      "nullable"    = true
                       "properties" = {
                         "limits" = {
+                          "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                          "nullable"    = true
                           "properties" = {
                             "cpu" = {
-                              "type" = "string"
+                              "nullable" = true
+                              "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                              "type"     = "string"
                             }
                             "memory" = {
-                              "type" = "string"
+                              "nullable" = true
+                              "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                              "type"     = "string"
                             }
                           }
-                          "required" = [
-                            "cpu",
-                            "memory",
-                          ]
                           "type" = "object"
                         }
+                        "preset" = {
+                          "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                          "enum" = [
+                            "Small",
+                            "Medium",
+                            "Large",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
                         "requests" = {
+                          "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                          "nullable"    = true
                           "properties" = {
                             "cpu" = {
-                              "type" = "string"
+                              "nullable" = true
+                              "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                              "type"     = "string"
                             }
                             "memory" = {
-                              "type" = "string"
+                              "nullable" = true
+                              "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                              "type"     = "string"
                             }
                           }
-                          "required" = [
-                            "cpu",
-                            "memory",
-                          ]
                           "type" = "object"
                         }
                       }
-                      "required" = [
-                        "limits",
-                        "requests",
-                      ]
+                      "type" = "object"
+                    }
+                    "scheduling" = {
+                      "description" = "Where the node's pod may run. Everything except `profile` is passed through to the PodSpec unchanged."
+                      "nullable"    = true
+                      "properties" = {
+                        "affinity" = {
+                          "description" = "Affinity is a group of affinity scheduling rules."
+                          "nullable"    = true
+                          "properties" = {
+                            "nodeAffinity" = {
+                              "description" = "Describes node affinity scheduling rules for the pod."
+                              "properties" = {
+                                "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node matches the corresponding matchExpressions; the node(s) with the highest sum are the most preferred."
+                                  "items" = {
+                                    "description" = "An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op)."
+                                    "properties" = {
+                                      "preference" = {
+                                        "description" = "A node selector term, associated with the corresponding weight."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "A list of node selector requirements by node's labels."
+                                            "items" = {
+                                              "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "The label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchFields" = {
+                                            "description" = "A list of node selector requirements by node's fields."
+                                            "items" = {
+                                              "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "The label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "weight" = {
+                                        "description" = "Weight associated with matching the corresponding nodeSelectorTerm, in the range 1-100."
+                                        "format"      = "int32"
+                                        "type"        = "integer"
+                                      }
+                                    }
+                                    "required" = [
+                                      "preference",
+                                      "weight",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to an update), the system may or may not try to eventually evict the pod from its node."
+                                  "properties" = {
+                                    "nodeSelectorTerms" = {
+                                      "description" = "Required. A list of node selector terms. The terms are ORed."
+                                      "items" = {
+                                        "description" = "A null or empty node selector term matches no objects. The requirements of them are ANDed. The TopologySelectorTerm type implements a subset of the NodeSelectorTerm."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "A list of node selector requirements by node's labels."
+                                            "items" = {
+                                              "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "The label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchFields" = {
+                                            "description" = "A list of node selector requirements by node's fields."
+                                            "items" = {
+                                              "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "The label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "required" = [
+                                    "nodeSelectorTerms",
+                                  ]
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "podAffinity" = {
+                              "description" = "Describes pod affinity scheduling rules (e.g. co-locate this pod in the same node, zone, etc. as some other pod(s))."
+                              "properties" = {
+                                "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                  "items" = {
+                                    "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                    "properties" = {
+                                      "podAffinityTerm" = {
+                                        "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "weight" = {
+                                        "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                        "format"      = "int32"
+                                        "type"        = "integer"
+                                      }
+                                    }
+                                    "required" = [
+                                      "podAffinityTerm",
+                                      "weight",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                  "items" = {
+                                    "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                    "properties" = {
+                                      "labelSelector" = {
+                                        "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                            "items" = {
+                                              "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "key is the label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchLabels" = {
+                                            "additionalProperties" = {
+                                              "type" = "string"
+                                            }
+                                            "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                            "type"        = "object"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "matchLabelKeys" = {
+                                        "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "mismatchLabelKeys" = {
+                                        "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "namespaceSelector" = {
+                                        "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                            "items" = {
+                                              "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "key is the label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchLabels" = {
+                                            "additionalProperties" = {
+                                              "type" = "string"
+                                            }
+                                            "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                            "type"        = "object"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "namespaces" = {
+                                        "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "topologyKey" = {
+                                        "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "topologyKey",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "podAntiAffinity" = {
+                              "description" = "Describes pod anti-affinity scheduling rules (e.g. avoid putting this pod in the same node, zone, etc. as some other pod(s))."
+                              "properties" = {
+                                "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                  "items" = {
+                                    "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                    "properties" = {
+                                      "podAffinityTerm" = {
+                                        "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "weight" = {
+                                        "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                        "format"      = "int32"
+                                        "type"        = "integer"
+                                      }
+                                    }
+                                    "required" = [
+                                      "podAffinityTerm",
+                                      "weight",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                                "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                  "description" = "If the anti-affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the anti-affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                  "items" = {
+                                    "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                    "properties" = {
+                                      "labelSelector" = {
+                                        "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                            "items" = {
+                                              "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "key is the label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchLabels" = {
+                                            "additionalProperties" = {
+                                              "type" = "string"
+                                            }
+                                            "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                            "type"        = "object"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "matchLabelKeys" = {
+                                        "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "mismatchLabelKeys" = {
+                                        "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "namespaceSelector" = {
+                                        "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                        "properties" = {
+                                          "matchExpressions" = {
+                                            "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                            "items" = {
+                                              "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                              "properties" = {
+                                                "key" = {
+                                                  "description" = "key is the label key that the selector applies to."
+                                                  "type"        = "string"
+                                                }
+                                                "operator" = {
+                                                  "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                  "type"        = "string"
+                                                }
+                                                "values" = {
+                                                  "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                  "items" = {
+                                                    "type" = "string"
+                                                  }
+                                                  "type" = "array"
+                                                }
+                                              }
+                                              "required" = [
+                                                "key",
+                                                "operator",
+                                              ]
+                                              "type" = "object"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "matchLabels" = {
+                                            "additionalProperties" = {
+                                              "type" = "string"
+                                            }
+                                            "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                            "type"        = "object"
+                                          }
+                                        }
+                                        "type" = "object"
+                                      }
+                                      "namespaces" = {
+                                        "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                        "items" = {
+                                          "type" = "string"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "topologyKey" = {
+                                        "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                        "type"        = "string"
+                                      }
+                                    }
+                                    "required" = [
+                                      "topologyKey",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "type" = "array"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "nodeSelector" = {
+                          "additionalProperties" = {
+                            "type" = "string"
+                          }
+                          "nullable" = true
+                          "type"     = "object"
+                        }
+                        "priorityClassName" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "profile" = {
+                          "description" = "Opt-in placement profile. Without one the pod runs on regular cluster nodes."
+                          "enum" = [
+                            "Fargate",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "runtimeClassName" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "tolerations" = {
+                          "items" = {
+                            "description" = "The pod this Toleration is attached to tolerates any taint that matches the triple <key,value,effect> using the matching operator <operator>."
+                            "properties" = {
+                              "effect" = {
+                                "description" = "Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute."
+                                "type"        = "string"
+                              }
+                              "key" = {
+                                "description" = "Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys."
+                                "type"        = "string"
+                              }
+                              "operator" = {
+                                "description" = "Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category."
+                                "type"        = "string"
+                              }
+                              "tolerationSeconds" = {
+                                "description" = "TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system."
+                                "format"      = "int64"
+                                "type"        = "integer"
+                              }
+                              "value" = {
+                                "description" = "Value is the taint value the toleration matches to. If the operator is Exists, the value should be empty, otherwise just a regular string."
+                                "type"        = "string"
+                              }
+                            }
+                            "type" = "object"
+                          }
+                          "nullable" = true
+                          "type"     = "array"
+                        }
+                        "topologySpreadConstraints" = {
+                          "items" = {
+                            "description" = "TopologySpreadConstraint specifies how to spread matching pods among the given topology."
+                            "properties" = {
+                              "labelSelector" = {
+                                "description" = "LabelSelector is used to find matching pods. Pods that match this label selector are counted to determine the number of pods in their corresponding topology domain."
+                                "properties" = {
+                                  "matchExpressions" = {
+                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                    "items" = {
+                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                      "properties" = {
+                                        "key" = {
+                                          "description" = "key is the label key that the selector applies to."
+                                          "type"        = "string"
+                                        }
+                                        "operator" = {
+                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                          "type"        = "string"
+                                        }
+                                        "values" = {
+                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                          "items" = {
+                                            "type" = "string"
+                                          }
+                                          "type" = "array"
+                                        }
+                                      }
+                                      "required" = [
+                                        "key",
+                                        "operator",
+                                      ]
+                                      "type" = "object"
+                                    }
+                                    "type" = "array"
+                                  }
+                                  "matchLabels" = {
+                                    "additionalProperties" = {
+                                      "type" = "string"
+                                    }
+                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                    "type"        = "object"
+                                  }
+                                }
+                                "type" = "object"
+                              }
+                              "matchLabelKeys" = {
+                                "description" = "MatchLabelKeys is a set of pod label keys to select the pods over which spreading will be calculated. The keys are used to lookup values from the incoming pod labels, those key-value labels are ANDed with labelSelector to select the group of existing pods over which spreading will be calculated for the incoming pod. The same key is forbidden to exist in both MatchLabelKeys and LabelSelector. MatchLabelKeys cannot be set when LabelSelector isn't set. Keys that don't exist in the incoming pod labels will be ignored. A null or empty list means only match against labelSelector.\n\nThis is a beta field and requires the MatchLabelKeysInPodTopologySpread feature gate to be enabled (enabled by default)."
+                                "items" = {
+                                  "type" = "string"
+                                }
+                                "type" = "array"
+                              }
+                              "maxSkew" = {
+                                "description" = "MaxSkew describes the degree to which pods may be unevenly distributed. When `whenUnsatisfiable=DoNotSchedule`, it is the maximum permitted difference between the number of matching pods in the target topology and the global minimum. The global minimum is the minimum number of matching pods in an eligible domain or zero if the number of eligible domains is less than MinDomains. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 2/2/1: In this case, the global minimum is 1. | zone1 | zone2 | zone3 | |  P P  |  P P  |   P   | - if MaxSkew is 1, incoming pod can only be scheduled to zone3 to become 2/2/2; scheduling it onto zone1(zone2) would make the ActualSkew(3-1) on zone1(zone2) violate MaxSkew(1). - if MaxSkew is 2, incoming pod can be scheduled onto any zone. When `whenUnsatisfiable=ScheduleAnyway`, it is used to give higher precedence to topologies that satisfy it. It's a required field. Default value is 1 and 0 is not allowed."
+                                "format"      = "int32"
+                                "type"        = "integer"
+                              }
+                              "minDomains" = {
+                                "description" = "MinDomains indicates a minimum number of eligible domains. When the number of eligible domains with matching topology keys is less than minDomains, Pod Topology Spread treats \"global minimum\" as 0, and then the calculation of Skew is performed. And when the number of eligible domains with matching topology keys equals or greater than minDomains, this value has no effect on scheduling. As a result, when the number of eligible domains is less than minDomains, scheduler won't schedule more than maxSkew Pods to those domains. If value is nil, the constraint behaves as if MinDomains is equal to 1. Valid values are integers greater than 0. When value is not nil, WhenUnsatisfiable must be DoNotSchedule.\n\nFor example, in a 3-zone cluster, MaxSkew is set to 2, MinDomains is set to 5 and pods with the same labelSelector spread as 2/2/2: | zone1 | zone2 | zone3 | |  P P  |  P P  |  P P  | The number of domains is less than 5(MinDomains), so \"global minimum\" is treated as 0. In this situation, new pod with the same labelSelector cannot be scheduled, because computed skew will be 3(3 - 0) if new Pod is scheduled to any of the three zones, it will violate MaxSkew."
+                                "format"      = "int32"
+                                "type"        = "integer"
+                              }
+                              "nodeAffinityPolicy" = {
+                                "description" = "NodeAffinityPolicy indicates how we will treat Pod's nodeAffinity/nodeSelector when calculating pod topology spread skew. Options are: - Honor: only nodes matching nodeAffinity/nodeSelector are included in the calculations. - Ignore: nodeAffinity/nodeSelector are ignored. All nodes are included in the calculations.\n\nIf this value is nil, the behavior is equivalent to the Honor policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                "type"        = "string"
+                              }
+                              "nodeTaintsPolicy" = {
+                                "description" = "NodeTaintsPolicy indicates how we will treat node taints when calculating pod topology spread skew. Options are: - Honor: nodes without taints, along with tainted nodes for which the incoming pod has a toleration, are included. - Ignore: node taints are ignored. All nodes are included.\n\nIf this value is nil, the behavior is equivalent to the Ignore policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                "type"        = "string"
+                              }
+                              "topologyKey" = {
+                                "description" = "TopologyKey is the key of node labels. Nodes that have a label with this key and identical values are considered to be in the same topology. We consider each <key, value> as a \"bucket\", and try to put balanced number of pods into each bucket. We define a domain as a particular instance of a topology. Also, we define an eligible domain as a domain whose nodes meet the requirements of nodeAffinityPolicy and nodeTaintsPolicy. e.g. If TopologyKey is \"kubernetes.io/hostname\", each Node is a domain of that topology. And, if TopologyKey is \"topology.kubernetes.io/zone\", each zone is a domain of that topology. It's a required field."
+                                "type"        = "string"
+                              }
+                              "whenUnsatisfiable" = {
+                                "description" = "WhenUnsatisfiable indicates how to deal with a pod if it doesn't satisfy the spread constraint. - DoNotSchedule (default) tells the scheduler not to schedule it. - ScheduleAnyway tells the scheduler to schedule the pod in any location,\n  but giving higher precedence to topologies that would help reduce the\n  skew.\nA constraint is considered \"Unsatisfiable\" for an incoming pod if and only if every possible node assignment for that pod would violate \"MaxSkew\" on some topology. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 3/1/1: | zone1 | zone2 | zone3 | | P P P |   P   |   P   | If WhenUnsatisfiable is set to DoNotSchedule, incoming pod can only be scheduled to zone2(zone3) to become 3/2/1(3/1/2) as ActualSkew(2-1) on zone2(zone3) satisfies MaxSkew(1). In other words, the cluster can still be imbalanced, but scheduler won't make it *more* imbalanced. It's a required field."
+                                "type"        = "string"
+                              }
+                            }
+                            "required" = [
+                              "maxSkew",
+                              "topologyKey",
+                              "whenUnsatisfiable",
+                            ]
+                            "type" = "object"
+                          }
+                          "nullable" = true
+                          "type"     = "array"
+                        }
+                      }
+                      "type" = "object"
+                    }
+                    "securityContext" = {
+                      "description" = "Security settings applied to the node's pod and all of its containers. Anything left out keeps the defaults, which satisfy the Pod Security Admission \"restricted\" profile."
+                      "nullable"    = true
+                      "properties" = {
+                        "addCapabilities" = {
+                          "description" = "Capabilities added back after dropping `ALL`."
+                          "items" = {
+                            "type" = "string"
+                          }
+                          "nullable" = true
+                          "type"     = "array"
+                        }
+                        "allowPrivilegeEscalation" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "fsGroup" = {
+                          "format"   = "int64"
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "readOnlyRootFilesystem" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "runAsGroup" = {
+                          "format"   = "int64"
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "runAsNonRoot" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "runAsUser" = {
+                          "format"   = "int64"
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "seccompLocalhostProfile" = {
+                          "description" = "Profile path, only used with `Localhost`."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "seccompProfile" = {
+                          "description" = "`RuntimeDefault`, `Unconfined` or `Localhost`."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                      }
                       "type" = "object"
                     }
                     "seedInput" = {
@@ -127,19 +2115,108 @@ resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodes_hydra_do
                 "status" = {
                   "nullable" = true
                   "properties" = {
+                    "accessTokenSecret" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "cardanoAddress" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "countedTransactions" = {
+                      "format"   = "int64"
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
+                    "dnsReady" = {
+                      "nullable" = true
+                      "type"     = "boolean"
+                    }
+                    "evictionBlockedAt" = {
+                      "description" = "Set while the pod sits on a cordoned node but cannot be evicted because its head needs it: since when, and which node."
+                      "nullable"    = true
+                      "type"        = "string"
+                    }
+                    "evictionBlockedNode" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
                     "externalUrl" = {
                       "type" = "string"
                     }
+                    "hydraVerificationKey" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "hydraVerificationKeyHash" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "lastActivityAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
                     "localUrl" = {
                       "type" = "string"
                     }
+                    "observedAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "openedAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "peerAddress" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "sleepReason" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "specError" = {
+                      "description" = "Why the node's deployment cannot be built from its spec, e.g. invalid resources. The pod keeps running with the last valid spec meanwhile."
+                      "nullable"    = true
+                      "type"        = "string"
+                    }
                     "state" = {
                       "type" = "string"
                     }
+                    "totalTransactions" = {
+                      "format"   = "int64"
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
                     "transactions" = {
                       "format" = "int64"
                       "type"   = "integer"
                     }
+                    "transitions" = {
+                      "items" = {
+                        "properties" = {
+                          "at" = {
+                            "type" = "string"
+                          }
+                          "state" = {
+                            "type" = "string"
+                          }
+                        }
+                        "required" = [
+                          "at",
+                          "state",
+                        ]
+                        "type" = "object"
+                      }
+                      "nullable" = true
+                      "type"     = "array"
+                    }
+                    "uptimeSeconds" = {
+                      "format"   = "uint64"
+                      "minimum"  = 0
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
                   }
                   "required" = [
                     "externalUrl",
@@ -167,3 +2244,4995 @@ resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodes_hydra_do
     }
   }
 }
+
+resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodepools_hydra_doom" {
+  manifest = {
+    "apiVersion" = "apiextensions.k8s.io/v1"
+    "kind"       = "CustomResourceDefinition"
+    "metadata" = {
+      "name" = "hydradoomnodepools.hydra.doom"
+    }
+    "spec" = {
+      "group" = "hydra.doom"
+      "names" = {
+        "categories" = [
+          "hydradoom",
+        ]
+        "kind"   = "HydraDoomNodePool"
+        "plural" = "hydradoomnodepools"
+        "shortNames" = [
+          "hydradoomnodepool",
+        ]
+        "singular" = "hydradoomnodepool"
+      }
+      "scope" = "Namespaced"
+      "versions" = [
+        {
+          "additionalPrinterColumns" = [
+            {
+              "jsonPath" = ".spec.replicas"
+              "name"     = "Replicas"
+              "type"     = "integer"
+            },
+            {
+              "jsonPath" = ".status.ready"
+              "name"     = "Ready"
+              "type"     = "integer"
+            },
+            {
+              "jsonPath" = ".status.warming"
+              "name"     = "Warming"
+              "type"     = "integer"
+            },
+            {
+              "jsonPath" = ".status.claimed"
+              "name"     = "Claimed"
+              "type"     = "integer"
+            },
+          ]
+          "name" = "v1alpha1"
+          "schema" = {
+            "openAPIV3Schema" = {
+              "description" = "Auto-generated derived type for HydraDoomNodePoolSpec via `CustomResource`"
+              "properties" = {
+                "spec" = {
+                  "properties" = {
+                    "claimTtlSeconds" = {
+                      "description" = "Claimed nodes are released (deleted) this long after being bound."
+                      "format"      = "uint64"
+                      "minimum"     = 0
+                      "nullable"    = true
+                      "type"        = "integer"
+                    }
+                    "replicas" = {
+                      "description" = "Number of unclaimed nodes kept warm."
+                      "format"      = "uint32"
+                      "minimum"     = 0
+                      "type"        = "integer"
+                    }
+                    "targetState" = {
+                      "enum" = [
+                        "Online",
+                        "HeadIsOpen",
+                      ]
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "template" = {
+                      "properties" = {
+                        "asleep" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "cardanoKeys" = {
+                          "description" = "Which Cardano key the node signs with."
+                          "enum" = [
+                            "Shared",
+                            "PerNode",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "commitInputs" = {
+                          "items" = {
+                            "type" = "string"
+                          }
+                          "type" = "array"
+                        }
+                        "containerResources" = {
+                          "description" = "Resources for the containers next to `main`, which is configured through `spec.resources`."
+                          "nullable"    = true
+                          "properties" = {
+                            "dmtrctl" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "openHead" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecar" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "head" = {
+                          "description" = "Nodes sharing the same head id are allowed to reach each other's peer port when network policies are enabled."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "hydraKeySecret" = {
+                          "description" = "Existing secret with `hydra.sk` and `hydra.vk` to use instead of a key pair generated by the operator. Label it `hydra.doom/roll-nodes: \"true\"` to restart the node when it changes."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "idlePolicy" = {
+                          "description" = "Opt-in policy that lets the operator put a node to sleep once its transaction counter has not moved for `idle_seconds`. Only nodes that are Online are considered; open heads and every other state are exempt."
+                          "nullable"    = true
+                          "properties" = {
+                            "idleSeconds" = {
+                              "format"  = "uint64"
+                              "minimum" = 0
+                              "type"    = "integer"
+                            }
+                          }
+                          "required" = [
+                            "idleSeconds",
+                          ]
+                          "type" = "object"
+                        }
+                        "networkId" = {
+                          "format"   = "uint8"
+                          "minimum"  = 0
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "offline" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "overrides" = {
+                          "description" = "Changes to the generated Deployment, Service, Ingress and ConfigMap, applied after everything else."
+                          "nullable"    = true
+                          "properties" = {
+                            "configmap" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "deployment" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "ingress" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "service" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "peerExposure" = {
+                          "description" = "How the hydra peer port is published for other parties of the head."
+                          "enum" = [
+                            "None",
+                            "ClusterIP",
+                            "NodePort",
+                            "LoadBalancer",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "probes" = {
+                          "description" = "Probe overrides. A probe given here replaces the generated default as a whole."
+                          "nullable"    = true
+                          "properties" = {
+                            "mainLiveness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "mainReadiness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "mainStartup" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecarLiveness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecarReadiness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "resources" = {
+                          "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                          "nullable"    = true
+                          "properties" = {
+                            "limits" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "preset" = {
+                              "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                              "enum" = [
+                                "Small",
+                                "Medium",
+                                "Large",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "requests" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "scheduling" = {
+                          "description" = "Where the node's pod may run. Everything except `profile` is passed through to the PodSpec unchanged."
+                          "nullable"    = true
+                          "properties" = {
+                            "affinity" = {
+                              "description" = "Affinity is a group of affinity scheduling rules."
+                              "nullable"    = true
+                              "properties" = {
+                                "nodeAffinity" = {
+                                  "description" = "Describes node affinity scheduling rules for the pod."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node matches the corresponding matchExpressions; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op)."
+                                        "properties" = {
+                                          "preference" = {
+                                            "description" = "A node selector term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "A list of node selector requirements by node's labels."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchFields" = {
+                                                "description" = "A list of node selector requirements by node's fields."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "Weight associated with matching the corresponding nodeSelectorTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "preference",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to an update), the system may or may not try to eventually evict the pod from its node."
+                                      "properties" = {
+                                        "nodeSelectorTerms" = {
+                                          "description" = "Required. A list of node selector terms. The terms are ORed."
+                                          "items" = {
+                                            "description" = "A null or empty node selector term matches no objects. The requirements of them are ANDed. The TopologySelectorTerm type implements a subset of the NodeSelectorTerm."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "A list of node selector requirements by node's labels."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchFields" = {
+                                                "description" = "A list of node selector requirements by node's fields."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "type" = "array"
+                                        }
+                                      }
+                                      "required" = [
+                                        "nodeSelectorTerms",
+                                      ]
+                                      "type" = "object"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "podAffinity" = {
+                                  "description" = "Describes pod affinity scheduling rules (e.g. co-locate this pod in the same node, zone, etc. as some other pod(s))."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                        "properties" = {
+                                          "podAffinityTerm" = {
+                                            "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "labelSelector" = {
+                                                "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "matchLabelKeys" = {
+                                                "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "mismatchLabelKeys" = {
+                                                "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "namespaceSelector" = {
+                                                "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "namespaces" = {
+                                                "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "topologyKey" = {
+                                                "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                                "type"        = "string"
+                                              }
+                                            }
+                                            "required" = [
+                                              "topologyKey",
+                                            ]
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "podAffinityTerm",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                      "items" = {
+                                        "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "podAntiAffinity" = {
+                                  "description" = "Describes pod anti-affinity scheduling rules (e.g. avoid putting this pod in the same node, zone, etc. as some other pod(s))."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                        "properties" = {
+                                          "podAffinityTerm" = {
+                                            "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "labelSelector" = {
+                                                "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "matchLabelKeys" = {
+                                                "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "mismatchLabelKeys" = {
+                                                "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "namespaceSelector" = {
+                                                "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "namespaces" = {
+                                                "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "topologyKey" = {
+                                                "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                                "type"        = "string"
+                                              }
+                                            }
+                                            "required" = [
+                                              "topologyKey",
+                                            ]
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "podAffinityTerm",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the anti-affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the anti-affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                      "items" = {
+                                        "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "nodeSelector" = {
+                              "additionalProperties" = {
+                                "type" = "string"
+                              }
+                              "nullable" = true
+                              "type"     = "object"
+                            }
+                            "priorityClassName" = {
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "profile" = {
+                              "description" = "Opt-in placement profile. Without one the pod runs on regular cluster nodes."
+                              "enum" = [
+                                "Fargate",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "runtimeClassName" = {
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "tolerations" = {
+                              "items" = {
+                                "description" = "The pod this Toleration is attached to tolerates any taint that matches the triple <key,value,effect> using the matching operator <operator>."
+                                "properties" = {
+                                  "effect" = {
+                                    "description" = "Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute."
+                                    "type"        = "string"
+                                  }
+                                  "key" = {
+                                    "description" = "Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys."
+                                    "type"        = "string"
+                                  }
+                                  "operator" = {
+                                    "description" = "Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category."
+                                    "type"        = "string"
+                                  }
+                                  "tolerationSeconds" = {
+                                    "description" = "TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system."
+                                    "format"      = "int64"
+                                    "type"        = "integer"
+                                  }
+                                  "value" = {
+                                    "description" = "Value is the taint value the toleration matches to. If the operator is Exists, the value should be empty, otherwise just a regular string."
+                                    "type"        = "string"
+                                  }
+                                }
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "topologySpreadConstraints" = {
+                              "items" = {
+                                "description" = "TopologySpreadConstraint specifies how to spread matching pods among the given topology."
+                                "properties" = {
+                                  "labelSelector" = {
+                                    "description" = "LabelSelector is used to find matching pods. Pods that match this label selector are counted to determine the number of pods in their corresponding topology domain."
+                                    "properties" = {
+                                      "matchExpressions" = {
+                                        "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                        "items" = {
+                                          "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                          "properties" = {
+                                            "key" = {
+                                              "description" = "key is the label key that the selector applies to."
+                                              "type"        = "string"
+                                            }
+                                            "operator" = {
+                                              "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                              "type"        = "string"
+                                            }
+                                            "values" = {
+                                              "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                              "items" = {
+                                                "type" = "string"
+                                              }
+                                              "type" = "array"
+                                            }
+                                          }
+                                          "required" = [
+                                            "key",
+                                            "operator",
+                                          ]
+                                          "type" = "object"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "matchLabels" = {
+                                        "additionalProperties" = {
+                                          "type" = "string"
+                                        }
+                                        "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                        "type"        = "object"
+                                      }
+                                    }
+                                    "type" = "object"
+                                  }
+                                  "matchLabelKeys" = {
+                                    "description" = "MatchLabelKeys is a set of pod label keys to select the pods over which spreading will be calculated. The keys are used to lookup values from the incoming pod labels, those key-value labels are ANDed with labelSelector to select the group of existing pods over which spreading will be calculated for the incoming pod. The same key is forbidden to exist in both MatchLabelKeys and LabelSelector. MatchLabelKeys cannot be set when LabelSelector isn't set. Keys that don't exist in the incoming pod labels will be ignored. A null or empty list means only match against labelSelector.\n\nThis is a beta field and requires the MatchLabelKeysInPodTopologySpread feature gate to be enabled (enabled by default)."
+                                    "items" = {
+                                      "type" = "string"
+                                    }
+                                    "type" = "array"
+                                  }
+                                  "maxSkew" = {
+                                    "description" = "MaxSkew describes the degree to which pods may be unevenly distributed. When `whenUnsatisfiable=DoNotSchedule`, it is the maximum permitted difference between the number of matching pods in the target topology and the global minimum. The global minimum is the minimum number of matching pods in an eligible domain or zero if the number of eligible domains is less than MinDomains. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 2/2/1: In this case, the global minimum is 1. | zone1 | zone2 | zone3 | |  P P  |  P P  |   P   | - if MaxSkew is 1, incoming pod can only be scheduled to zone3 to become 2/2/2; scheduling it onto zone1(zone2) would make the ActualSkew(3-1) on zone1(zone2) violate MaxSkew(1). - if MaxSkew is 2, incoming pod can be scheduled onto any zone. When `whenUnsatisfiable=ScheduleAnyway`, it is used to give higher precedence to topologies that satisfy it. It's a required field. Default value is 1 and 0 is not allowed."
+                                    "format"      = "int32"
+                                    "type"        = "integer"
+                                  }
+                                  "minDomains" = {
+                                    "description" = "MinDomains indicates a minimum number of eligible domains. When the number of eligible domains with matching topology keys is less than minDomains, Pod Topology Spread treats \"global minimum\" as 0, and then the calculation of Skew is performed. And when the number of eligible domains with matching topology keys equals or greater than minDomains, this value has no effect on scheduling. As a result, when the number of eligible domains is less than minDomains, scheduler won't schedule more than maxSkew Pods to those domains. If value is nil, the constraint behaves as if MinDomains is equal to 1. Valid values are integers greater than 0. When value is not nil, WhenUnsatisfiable must be DoNotSchedule.\n\nFor example, in a 3-zone cluster, MaxSkew is set to 2, MinDomains is set to 5 and pods with the same labelSelector spread as 2/2/2: | zone1 | zone2 | zone3 | |  P P  |  P P  |  P P  | The number of domains is less than 5(MinDomains), so \"global minimum\" is treated as 0. In this situation, new pod with the same labelSelector cannot be scheduled, because computed skew will be 3(3 - 0) if new Pod is scheduled to any of the three zones, it will violate MaxSkew."
+                                    "format"      = "int32"
+                                    "type"        = "integer"
+                                  }
+                                  "nodeAffinityPolicy" = {
+                                    "description" = "NodeAffinityPolicy indicates how we will treat Pod's nodeAffinity/nodeSelector when calculating pod topology spread skew. Options are: - Honor: only nodes matching nodeAffinity/nodeSelector are included in the calculations. - Ignore: nodeAffinity/nodeSelector are ignored. All nodes are included in the calculations.\n\nIf this value is nil, the behavior is equivalent to the Honor policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                    "type"        = "string"
+                                  }
+                                  "nodeTaintsPolicy" = {
+                                    "description" = "NodeTaintsPolicy indicates how we will treat node taints when calculating pod topology spread skew. Options are: - Honor: nodes without taints, along with tainted nodes for which the incoming pod has a toleration, are included. - Ignore: node taints are ignored. All nodes are included.\n\nIf this value is nil, the behavior is equivalent to the Ignore policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                    "type"        = "string"
+                                  }
+                                  "topologyKey" = {
+                                    "description" = "TopologyKey is the key of node labels. Nodes that have a label with this key and identical values are considered to be in the same topology. We consider each <key, value> as a \"bucket\", and try to put balanced number of pods into each bucket. We define a domain as a particular instance of a topology. Also, we define an eligible domain as a domain whose nodes meet the requirements of nodeAffinityPolicy and nodeTaintsPolicy. e.g. If TopologyKey is \"kubernetes.io/hostname\", each Node is a domain of that topology. And, if TopologyKey is \"topology.kubernetes.io/zone\", each zone is a domain of that topology. It's a required field."
+                                    "type"        = "string"
+                                  }
+                                  "whenUnsatisfiable" = {
+                                    "description" = "WhenUnsatisfiable indicates how to deal with a pod if it doesn't satisfy the spread constraint. - DoNotSchedule (default) tells the scheduler not to schedule it. - ScheduleAnyway tells the scheduler to schedule the pod in any location,\n  but giving higher precedence to topologies that would help reduce the\n  skew.\nA constraint is considered \"Unsatisfiable\" for an incoming pod if and only if every possible node assignment for that pod would violate \"MaxSkew\" on some topology. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 3/1/1: | zone1 | zone2 | zone3 | | P P P |   P   |   P   | If WhenUnsatisfiable is set to DoNotSchedule, incoming pod can only be scheduled to zone2(zone3) to become 3/2/1(3/1/2) as ActualSkew(2-1) on zone2(zone3) satisfies MaxSkew(1). In other words, the cluster can still be imbalanced, but scheduler won't make it *more* imbalanced. It's a required field."
+                                    "type"        = "string"
+                                  }
+                                }
+                                "required" = [
+                                  "maxSkew",
+                                  "topologyKey",
+                                  "whenUnsatisfiable",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "securityContext" = {
+                          "description" = "Security settings applied to the node's pod and all of its containers. Anything left out keeps the defaults, which satisfy the Pod Security Admission \"restricted\" profile."
+                          "nullable"    = true
+                          "properties" = {
+                            "addCapabilities" = {
+                              "description" = "Capabilities added back after dropping `ALL`."
+                              "items" = {
+                                "type" = "string"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "allowPrivilegeEscalation" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "fsGroup" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "readOnlyRootFilesystem" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "runAsGroup" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "runAsNonRoot" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "runAsUser" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "seccompLocalhostProfile" = {
+                              "description" = "Profile path, only used with `Localhost`."
+                              "nullable"    = true
+                              "type"        = "string"
+                            }
+                            "seccompProfile" = {
+                              "description" = "`RuntimeDefault`, `Unconfined` or `Localhost`."
+                              "nullable"    = true
+                              "type"        = "string"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "seedInput" = {
+                          "type" = "string"
+                        }
+                        "startChainFrom" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                      }
+                      "required" = [
+                        "commitInputs",
+                        "seedInput",
+                      ]
+                      "type" = "object"
+                    }
+                  }
+                  "required" = [
+                    "replicas",
+                    "template",
+                  ]
+                  "type" = "object"
+                }
+                "status" = {
+                  "nullable" = true
+                  "properties" = {
+                    "claimed" = {
+                      "format"  = "uint32"
+                      "minimum" = 0
+                      "type"    = "integer"
+                    }
+                    "ready" = {
+                      "format"  = "uint32"
+                      "minimum" = 0
+                      "type"    = "integer"
+                    }
+                    "warming" = {
+                      "format"  = "uint32"
+                      "minimum" = 0
+                      "type"    = "integer"
+                    }
+                  }
+                  "required" = [
+                    "claimed",
+                    "ready",
+                    "warming",
+                  ]
+                  "type" = "object"
+                }
+              }
+              "required" = [
+                "spec",
+              ]
+              "title" = "HydraDoomNodePool"
+              "type"  = "object"
+            }
+          }
+          "served"  = true
+          "storage" = true
+          "subresources" = {
+            "status" = {}
+          }
+        },
+      ]
+    }
+  }
+}
+
+resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodeclaims_hydra_doom" {
+  manifest = {
+    "apiVersion" = "apiextensions.k8s.io/v1"
+    "kind"       = "CustomResourceDefinition"
+    "metadata" = {
+      "name" = "hydradoomnodeclaims.hydra.doom"
+    }
+    "spec" = {
+      "group" = "hydra.doom"
+      "names" = {
+        "categories" = [
+          "hydradoom",
+        ]
+        "kind"   = "HydraDoomNodeClaim"
+        "plural" = "hydradoomnodeclaims"
+        "shortNames" = [
+          "hydradoomnodeclaim",
+        ]
+        "singular" = "hydradoomnodeclaim"
+      }
+      "scope" = "Namespaced"
+      "versions" = [
+        {
+          "additionalPrinterColumns" = [
+            {
+              "jsonPath" = ".spec.pool"
+              "name"     = "Pool"
+              "type"     = "string"
+            },
+            {
+              "jsonPath" = ".status.phase"
+              "name"     = "Phase"
+              "type"     = "string"
+            },
+            {
+              "jsonPath" = ".status.node"
+              "name"     = "Node"
+              "type"     = "string"
+            },
+            {
+              "jsonPath" = ".status.externalUrl"
+              "name"     = "External URI"
+              "type"     = "string"
+            },
+          ]
+          "name" = "v1alpha1"
+          "schema" = {
+            "openAPIV3Schema" = {
+              "description" = "Auto-generated derived type for HydraDoomNodeClaimSpec via `CustomResource`"
+              "properties" = {
+                "spec" = {
+                  "properties" = {
+                    "pool" = {
+                      "type" = "string"
+                    }
+                  }
+                  "required" = [
+                    "pool",
+                  ]
+                  "type" = "object"
+                }
+                "status" = {
+                  "nullable" = true
+                  "properties" = {
+                    "boundAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "externalUrl" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "node" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "phase" = {
+                      "type" = "string"
+                    }
+                  }
+                  "required" = [
+                    "phase",
+                  ]
+                  "type" = "object"
+                }
+              }
+              "required" = [
+                "spec",
+              ]
+              "title" = "HydraDoomNodeClaim"
+              "type"  = "object"
+            }
+          }
+          "served"  = true
+          "storage" = true
+          "subresources" = {
+            "status" = {}
+          }
+        },
+      ]
+    }
+  }
+}
+
+resource "kubernetes_manifest" "customresourcedefinition_hydradoomnodereports_hydra_doom" {
+  manifest = {
+    "apiVersion" = "apiextensions.k8s.io/v1"
+    "kind"       = "CustomResourceDefinition"
+    "metadata" = {
+      "name" = "hydradoomnodereports.hydra.doom"
+    }
+    "spec" = {
+      "group" = "hydra.doom"
+      "names" = {
+        "categories" = [
+          "hydradoom",
+        ]
+        "kind"   = "HydraDoomNodeReport"
+        "plural" = "hydradoomnodereports"
+        "shortNames" = [
+          "hydradoomnodereport",
+        ]
+        "singular" = "hydradoomnodereport"
+      }
+      "scope" = "Namespaced"
+      "versions" = [
+        {
+          "additionalPrinterColumns" = [
+            {
+              "jsonPath" = ".spec.node"
+              "name"     = "Node"
+              "type"     = "string"
+            },
+            {
+              "jsonPath" = ".spec.totalTransactions"
+              "name"     = "Transactions"
+              "type"     = "integer"
+            },
+            {
+              "jsonPath" = ".spec.timeToOpenSeconds"
+              "name"     = "Time To Open"
+              "type"     = "integer"
+            },
+            {
+              "jsonPath" = ".spec.uptimeSeconds"
+              "name"     = "Uptime"
+              "type"     = "integer"
+            },
+          ]
+          "name" = "v1alpha1"
+          "schema" = {
+            "openAPIV3Schema" = {
+              "description" = "Auto-generated derived type for HydraDoomNodeReportSpec via `CustomResource`"
+              "properties" = {
+                "spec" = {
+                  "description" = "Immutable record of a node's run, written when the node is deleted."
+                  "properties" = {
+                    "createdAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "deletedAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "finalStatus" = {
+                      "nullable" = true
+                      "properties" = {
+                        "accessTokenSecret" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "cardanoAddress" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "countedTransactions" = {
+                          "format"   = "int64"
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "dnsReady" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "evictionBlockedAt" = {
+                          "description" = "Set while the pod sits on a cordoned node but cannot be evicted because its head needs it: since when, and which node."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "evictionBlockedNode" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "externalUrl" = {
+                          "type" = "string"
+                        }
+                        "hydraVerificationKey" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "hydraVerificationKeyHash" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "lastActivityAt" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "localUrl" = {
+                          "type" = "string"
+                        }
+                        "observedAt" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "openedAt" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "peerAddress" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "sleepReason" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "specError" = {
+                          "description" = "Why the node's deployment cannot be built from its spec, e.g. invalid resources. The pod keeps running with the last valid spec meanwhile."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "state" = {
+                          "type" = "string"
+                        }
+                        "totalTransactions" = {
+                          "format"   = "int64"
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "transactions" = {
+                          "format" = "int64"
+                          "type"   = "integer"
+                        }
+                        "transitions" = {
+                          "items" = {
+                            "properties" = {
+                              "at" = {
+                                "type" = "string"
+                              }
+                              "state" = {
+                                "type" = "string"
+                              }
+                            }
+                            "required" = [
+                              "at",
+                              "state",
+                            ]
+                            "type" = "object"
+                          }
+                          "nullable" = true
+                          "type"     = "array"
+                        }
+                        "uptimeSeconds" = {
+                          "format"   = "uint64"
+                          "minimum"  = 0
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                      }
+                      "required" = [
+                        "externalUrl",
+                        "localUrl",
+                        "state",
+                        "transactions",
+                      ]
+                      "type" = "object"
+                    }
+                    "node" = {
+                      "type" = "string"
+                    }
+                    "nodeSpec" = {
+                      "properties" = {
+                        "asleep" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "cardanoKeys" = {
+                          "description" = "Which Cardano key the node signs with."
+                          "enum" = [
+                            "Shared",
+                            "PerNode",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "commitInputs" = {
+                          "items" = {
+                            "type" = "string"
+                          }
+                          "type" = "array"
+                        }
+                        "containerResources" = {
+                          "description" = "Resources for the containers next to `main`, which is configured through `spec.resources`."
+                          "nullable"    = true
+                          "properties" = {
+                            "dmtrctl" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "openHead" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecar" = {
+                              "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                              "nullable"    = true
+                              "properties" = {
+                                "limits" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "preset" = {
+                                  "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                                  "enum" = [
+                                    "Small",
+                                    "Medium",
+                                    "Large",
+                                  ]
+                                  "nullable" = true
+                                  "type"     = "string"
+                                }
+                                "requests" = {
+                                  "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                                  "nullable"    = true
+                                  "properties" = {
+                                    "cpu" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                    "memory" = {
+                                      "nullable" = true
+                                      "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                      "type"     = "string"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "head" = {
+                          "description" = "Nodes sharing the same head id are allowed to reach each other's peer port when network policies are enabled."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "hydraKeySecret" = {
+                          "description" = "Existing secret with `hydra.sk` and `hydra.vk` to use instead of a key pair generated by the operator. Label it `hydra.doom/roll-nodes: \"true\"` to restart the node when it changes."
+                          "nullable"    = true
+                          "type"        = "string"
+                        }
+                        "idlePolicy" = {
+                          "description" = "Opt-in policy that lets the operator put a node to sleep once its transaction counter has not moved for `idle_seconds`. Only nodes that are Online are considered; open heads and every other state are exempt."
+                          "nullable"    = true
+                          "properties" = {
+                            "idleSeconds" = {
+                              "format"  = "uint64"
+                              "minimum" = 0
+                              "type"    = "integer"
+                            }
+                          }
+                          "required" = [
+                            "idleSeconds",
+                          ]
+                          "type" = "object"
+                        }
+                        "networkId" = {
+                          "format"   = "uint8"
+                          "minimum"  = 0
+                          "nullable" = true
+                          "type"     = "integer"
+                        }
+                        "offline" = {
+                          "nullable" = true
+                          "type"     = "boolean"
+                        }
+                        "overrides" = {
+                          "description" = "Changes to the generated Deployment, Service, Ingress and ConfigMap, applied after everything else."
+                          "nullable"    = true
+                          "properties" = {
+                            "configmap" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "deployment" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "ingress" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "service" = {
+                              "nullable" = true
+                              "properties" = {
+                                "jsonPatch" = {
+                                  "description" = "RFC 6902 operations applied after `merge`."
+                                  "items" = {
+                                    "properties" = {
+                                      "from" = {
+                                        "nullable" = true
+                                        "type"     = "string"
+                                      }
+                                      "op" = {
+                                        "enum" = [
+                                          "add",
+                                          "remove",
+                                          "replace",
+                                          "move",
+                                          "copy",
+                                          "test",
+                                        ]
+                                        "type" = "string"
+                                      }
+                                      "path" = {
+                                        "type" = "string"
+                                      }
+                                      "value" = {
+                                        "nullable"                             = true
+                                        "x-kubernetes-preserve-unknown-fields" = true
+                                      }
+                                    }
+                                    "required" = [
+                                      "op",
+                                      "path",
+                                    ]
+                                    "type" = "object"
+                                  }
+                                  "nullable" = true
+                                  "type"     = "array"
+                                }
+                                "merge" = {
+                                  "description"                          = "Merged into the generated object. Objects are merged key by key and `null` removes a key. Lists with a Kubernetes merge key are merged item by item: containers, env, volumes and imagePullSecrets by `name`, volumeMounts by `mountPath`, container ports by `containerPort`, service ports by `port` and hostAliases by `ip`. An item with `$patch: delete` removes its match. Other lists are replaced."
+                                  "nullable"                             = true
+                                  "type"                                 = "object"
+                                  "x-kubernetes-preserve-unknown-fields" = true
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "peerExposure" = {
+                          "description" = "How the hydra peer port is published for other parties of the head."
+                          "enum" = [
+                            "None",
+                            "ClusterIP",
+                            "NodePort",
+                            "LoadBalancer",
+                          ]
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                        "probes" = {
+                          "description" = "Probe overrides. A probe given here replaces the generated default as a whole."
+                          "nullable"    = true
+                          "properties" = {
+                            "mainLiveness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "mainReadiness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "mainStartup" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecarLiveness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "sidecarReadiness" = {
+                              "description" = "Probe describes a health check to be performed against a container to determine whether it is alive or ready to receive traffic."
+                              "nullable"    = true
+                              "properties" = {
+                                "exec" = {
+                                  "description" = "Exec specifies the action to take."
+                                  "properties" = {
+                                    "command" = {
+                                      "description" = "Command is the command line to execute inside the container, the working directory for the command  is root ('/') in the container's filesystem. The command is simply exec'd, it is not run inside a shell, so traditional shell instructions ('|', etc) won't work. To use a shell, you need to explicitly call out to that shell. Exit status of 0 is treated as live/healthy and non-zero is unhealthy."
+                                      "items" = {
+                                        "type" = "string"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "failureThreshold" = {
+                                  "description" = "Minimum consecutive failures for the probe to be considered failed after having succeeded. Defaults to 3. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "grpc" = {
+                                  "description" = "GRPC specifies an action involving a GRPC port."
+                                  "properties" = {
+                                    "port" = {
+                                      "description" = "Port number of the gRPC service. Number must be in the range 1 to 65535."
+                                      "format"      = "int32"
+                                      "type"        = "integer"
+                                    }
+                                    "service" = {
+                                      "description" = "Service is the name of the service to place in the gRPC HealthCheckRequest (see https://github.com/grpc/grpc/blob/master/doc/health-checking.md).\n\nIf this is not specified, the default behavior is defined by gRPC."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "httpGet" = {
+                                  "description" = "HTTPGet specifies the http request to perform."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Host name to connect to, defaults to the pod IP. You probably want to set \"Host\" in httpHeaders instead."
+                                      "type"        = "string"
+                                    }
+                                    "httpHeaders" = {
+                                      "description" = "Custom headers to set in the request. HTTP allows repeated headers."
+                                      "items" = {
+                                        "description" = "HTTPHeader describes a custom header to be used in HTTP probes"
+                                        "properties" = {
+                                          "name" = {
+                                            "description" = "The header field name. This will be canonicalized upon output, so case-variant names will be understood as the same header."
+                                            "type"        = "string"
+                                          }
+                                          "value" = {
+                                            "description" = "The header field value"
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "name",
+                                          "value",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "path" = {
+                                      "description" = "Path to access on the HTTP server."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Name or number of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                    "scheme" = {
+                                      "description" = "Scheme to use for connecting to the host. Defaults to HTTP."
+                                      "type"        = "string"
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "initialDelaySeconds" = {
+                                  "description" = "Number of seconds after the container has started before liveness probes are initiated. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "periodSeconds" = {
+                                  "description" = "How often (in seconds) to perform the probe. Default to 10 seconds. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "successThreshold" = {
+                                  "description" = "Minimum consecutive successes for the probe to be considered successful after having failed. Defaults to 1. Must be 1 for liveness and startup. Minimum value is 1."
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                                "tcpSocket" = {
+                                  "description" = "TCPSocket specifies an action involving a TCP port."
+                                  "properties" = {
+                                    "host" = {
+                                      "description" = "Optional: Host name to connect to, defaults to the pod IP."
+                                      "type"        = "string"
+                                    }
+                                    "port" = {
+                                      "description"                = "Number or name of the port to access on the container. Number must be in the range 1 to 65535. Name must be an IANA_SVC_NAME."
+                                      "x-kubernetes-int-or-string" = true
+                                    }
+                                  }
+                                  "required" = [
+                                    "port",
+                                  ]
+                                  "type" = "object"
+                                }
+                                "terminationGracePeriodSeconds" = {
+                                  "description" = "Optional duration in seconds the pod needs to terminate gracefully upon probe failure. The grace period is the duration in seconds after the processes running in the pod are sent a termination signal and the time when the processes are forcibly halted with a kill signal. Set this value longer than the expected cleanup time for your process. If this value is nil, the pod's terminationGracePeriodSeconds will be used. Otherwise, this value overrides the value provided by the pod spec. Value must be non-negative integer. The value zero indicates stop immediately via the kill signal (no opportunity to shut down). This is a beta field and requires enabling ProbeTerminationGracePeriod feature gate. Minimum value is 1. spec.terminationGracePeriodSeconds is used if unset."
+                                  "format"      = "int64"
+                                  "type"        = "integer"
+                                }
+                                "timeoutSeconds" = {
+                                  "description" = "Number of seconds after which the probe times out. Defaults to 1 second. Minimum value is 1. More info: https://kubernetes.io/docs/concepts/workloads/pods/pod-lifecycle#container-probes"
+                                  "format"      = "int32"
+                                  "type"        = "integer"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "resources" = {
+                          "description" = "Requests and limits of one container. Explicit quantities override the ones of the preset."
+                          "nullable"    = true
+                          "properties" = {
+                            "limits" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "preset" = {
+                              "description" = "Named sizes, so that most nodes do not have to spell out quantities."
+                              "enum" = [
+                                "Small",
+                                "Medium",
+                                "Large",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "requests" = {
+                              "description" = "Quantities are checked against the same pattern the API server uses for `resource.Quantity`."
+                              "nullable"    = true
+                              "properties" = {
+                                "cpu" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                                "memory" = {
+                                  "nullable" = true
+                                  "pattern"  = "^(\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\\+|-)?(([0-9]+(\\.[0-9]*)?)|(\\.[0-9]+))))?$"
+                                  "type"     = "string"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "scheduling" = {
+                          "description" = "Where the node's pod may run. Everything except `profile` is passed through to the PodSpec unchanged."
+                          "nullable"    = true
+                          "properties" = {
+                            "affinity" = {
+                              "description" = "Affinity is a group of affinity scheduling rules."
+                              "nullable"    = true
+                              "properties" = {
+                                "nodeAffinity" = {
+                                  "description" = "Describes node affinity scheduling rules for the pod."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node matches the corresponding matchExpressions; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "An empty preferred scheduling term matches all objects with implicit weight 0 (i.e. it's a no-op). A null preferred scheduling term matches no objects (i.e. is also a no-op)."
+                                        "properties" = {
+                                          "preference" = {
+                                            "description" = "A node selector term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "A list of node selector requirements by node's labels."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchFields" = {
+                                                "description" = "A list of node selector requirements by node's fields."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "Weight associated with matching the corresponding nodeSelectorTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "preference",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to an update), the system may or may not try to eventually evict the pod from its node."
+                                      "properties" = {
+                                        "nodeSelectorTerms" = {
+                                          "description" = "Required. A list of node selector terms. The terms are ORed."
+                                          "items" = {
+                                            "description" = "A null or empty node selector term matches no objects. The requirements of them are ANDed. The TopologySelectorTerm type implements a subset of the NodeSelectorTerm."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "A list of node selector requirements by node's labels."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchFields" = {
+                                                "description" = "A list of node selector requirements by node's fields."
+                                                "items" = {
+                                                  "description" = "A node selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "The label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "Represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists, DoesNotExist. Gt, and Lt."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "An array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. If the operator is Gt or Lt, the values array must have a single element, which will be interpreted as an integer. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "type" = "array"
+                                        }
+                                      }
+                                      "required" = [
+                                        "nodeSelectorTerms",
+                                      ]
+                                      "type" = "object"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "podAffinity" = {
+                                  "description" = "Describes pod affinity scheduling rules (e.g. co-locate this pod in the same node, zone, etc. as some other pod(s))."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                        "properties" = {
+                                          "podAffinityTerm" = {
+                                            "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "labelSelector" = {
+                                                "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "matchLabelKeys" = {
+                                                "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "mismatchLabelKeys" = {
+                                                "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "namespaceSelector" = {
+                                                "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "namespaces" = {
+                                                "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "topologyKey" = {
+                                                "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                                "type"        = "string"
+                                              }
+                                            }
+                                            "required" = [
+                                              "topologyKey",
+                                            ]
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "podAffinityTerm",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                      "items" = {
+                                        "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                                "podAntiAffinity" = {
+                                  "description" = "Describes pod anti-affinity scheduling rules (e.g. avoid putting this pod in the same node, zone, etc. as some other pod(s))."
+                                  "properties" = {
+                                    "preferredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "The scheduler will prefer to schedule pods to nodes that satisfy the anti-affinity expressions specified by this field, but it may choose a node that violates one or more of the expressions. The node that is most preferred is the one with the greatest sum of weights, i.e. for each node that meets all of the scheduling requirements (resource request, requiredDuringScheduling anti-affinity expressions, etc.), compute a sum by iterating through the elements of this field and adding \"weight\" to the sum if the node has pods which matches the corresponding podAffinityTerm; the node(s) with the highest sum are the most preferred."
+                                      "items" = {
+                                        "description" = "The weights of all of the matched WeightedPodAffinityTerm fields are added per-node to find the most preferred node(s)"
+                                        "properties" = {
+                                          "podAffinityTerm" = {
+                                            "description" = "Required. A pod affinity term, associated with the corresponding weight."
+                                            "properties" = {
+                                              "labelSelector" = {
+                                                "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "matchLabelKeys" = {
+                                                "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "mismatchLabelKeys" = {
+                                                "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "namespaceSelector" = {
+                                                "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                                "properties" = {
+                                                  "matchExpressions" = {
+                                                    "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                    "items" = {
+                                                      "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                      "properties" = {
+                                                        "key" = {
+                                                          "description" = "key is the label key that the selector applies to."
+                                                          "type"        = "string"
+                                                        }
+                                                        "operator" = {
+                                                          "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                          "type"        = "string"
+                                                        }
+                                                        "values" = {
+                                                          "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                          "items" = {
+                                                            "type" = "string"
+                                                          }
+                                                          "type" = "array"
+                                                        }
+                                                      }
+                                                      "required" = [
+                                                        "key",
+                                                        "operator",
+                                                      ]
+                                                      "type" = "object"
+                                                    }
+                                                    "type" = "array"
+                                                  }
+                                                  "matchLabels" = {
+                                                    "additionalProperties" = {
+                                                      "type" = "string"
+                                                    }
+                                                    "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                    "type"        = "object"
+                                                  }
+                                                }
+                                                "type" = "object"
+                                              }
+                                              "namespaces" = {
+                                                "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                                "items" = {
+                                                  "type" = "string"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "topologyKey" = {
+                                                "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                                "type"        = "string"
+                                              }
+                                            }
+                                            "required" = [
+                                              "topologyKey",
+                                            ]
+                                            "type" = "object"
+                                          }
+                                          "weight" = {
+                                            "description" = "weight associated with matching the corresponding podAffinityTerm, in the range 1-100."
+                                            "format"      = "int32"
+                                            "type"        = "integer"
+                                          }
+                                        }
+                                        "required" = [
+                                          "podAffinityTerm",
+                                          "weight",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                    "requiredDuringSchedulingIgnoredDuringExecution" = {
+                                      "description" = "If the anti-affinity requirements specified by this field are not met at scheduling time, the pod will not be scheduled onto the node. If the anti-affinity requirements specified by this field cease to be met at some point during pod execution (e.g. due to a pod label update), the system may or may not try to eventually evict the pod from its node. When there are multiple elements, the lists of nodes corresponding to each podAffinityTerm are intersected, i.e. all terms must be satisfied."
+                                      "items" = {
+                                        "description" = "Defines a set of pods (namely those matching the labelSelector relative to the given namespace(s)) that this pod should be co-located (affinity) or not co-located (anti-affinity) with, where co-located is defined as running on a node whose value of the label with key <topologyKey> matches that of any node on which a pod of the set of pods is running"
+                                        "properties" = {
+                                          "labelSelector" = {
+                                            "description" = "A label query over a set of resources, in this case pods. If it's null, this PodAffinityTerm matches with no Pods."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "matchLabelKeys" = {
+                                            "description" = "MatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key in (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both matchLabelKeys and labelSelector. Also, matchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "mismatchLabelKeys" = {
+                                            "description" = "MismatchLabelKeys is a set of pod label keys to select which pods will be taken into consideration. The keys are used to lookup values from the incoming pod labels, those key-value labels are merged with `labelSelector` as `key notin (value)` to select the group of existing pods which pods will be taken into consideration for the incoming pod's pod (anti) affinity. Keys that don't exist in the incoming pod labels will be ignored. The default value is empty. The same key is forbidden to exist in both mismatchLabelKeys and labelSelector. Also, mismatchLabelKeys cannot be set when labelSelector isn't set. This is a beta field and requires enabling MatchLabelKeysInPodAffinity feature gate (enabled by default)."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "namespaceSelector" = {
+                                            "description" = "A label query over the set of namespaces that the term applies to. The term is applied to the union of the namespaces selected by this field and the ones listed in the namespaces field. null selector and null or empty namespaces list means \"this pod's namespace\". An empty selector ({}) matches all namespaces."
+                                            "properties" = {
+                                              "matchExpressions" = {
+                                                "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                                "items" = {
+                                                  "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                                  "properties" = {
+                                                    "key" = {
+                                                      "description" = "key is the label key that the selector applies to."
+                                                      "type"        = "string"
+                                                    }
+                                                    "operator" = {
+                                                      "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                                      "type"        = "string"
+                                                    }
+                                                    "values" = {
+                                                      "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                                      "items" = {
+                                                        "type" = "string"
+                                                      }
+                                                      "type" = "array"
+                                                    }
+                                                  }
+                                                  "required" = [
+                                                    "key",
+                                                    "operator",
+                                                  ]
+                                                  "type" = "object"
+                                                }
+                                                "type" = "array"
+                                              }
+                                              "matchLabels" = {
+                                                "additionalProperties" = {
+                                                  "type" = "string"
+                                                }
+                                                "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                                "type"        = "object"
+                                              }
+                                            }
+                                            "type" = "object"
+                                          }
+                                          "namespaces" = {
+                                            "description" = "namespaces specifies a static list of namespace names that the term applies to. The term is applied to the union of the namespaces listed in this field and the ones selected by namespaceSelector. null or empty namespaces list and null namespaceSelector means \"this pod's namespace\"."
+                                            "items" = {
+                                              "type" = "string"
+                                            }
+                                            "type" = "array"
+                                          }
+                                          "topologyKey" = {
+                                            "description" = "This pod should be co-located (affinity) or not co-located (anti-affinity) with the pods matching the labelSelector in the specified namespaces, where co-located is defined as running on a node whose value of the label with key topologyKey matches that of any node on which any of the selected pods is running. Empty topologyKey is not allowed."
+                                            "type"        = "string"
+                                          }
+                                        }
+                                        "required" = [
+                                          "topologyKey",
+                                        ]
+                                        "type" = "object"
+                                      }
+                                      "type" = "array"
+                                    }
+                                  }
+                                  "type" = "object"
+                                }
+                              }
+                              "type" = "object"
+                            }
+                            "nodeSelector" = {
+                              "additionalProperties" = {
+                                "type" = "string"
+                              }
+                              "nullable" = true
+                              "type"     = "object"
+                            }
+                            "priorityClassName" = {
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "profile" = {
+                              "description" = "Opt-in placement profile. Without one the pod runs on regular cluster nodes."
+                              "enum" = [
+                                "Fargate",
+                              ]
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "runtimeClassName" = {
+                              "nullable" = true
+                              "type"     = "string"
+                            }
+                            "tolerations" = {
+                              "items" = {
+                                "description" = "The pod this Toleration is attached to tolerates any taint that matches the triple <key,value,effect> using the matching operator <operator>."
+                                "properties" = {
+                                  "effect" = {
+                                    "description" = "Effect indicates the taint effect to match. Empty means match all taint effects. When specified, allowed values are NoSchedule, PreferNoSchedule and NoExecute."
+                                    "type"        = "string"
+                                  }
+                                  "key" = {
+                                    "description" = "Key is the taint key that the toleration applies to. Empty means match all taint keys. If the key is empty, operator must be Exists; this combination means to match all values and all keys."
+                                    "type"        = "string"
+                                  }
+                                  "operator" = {
+                                    "description" = "Operator represents a key's relationship to the value. Valid operators are Exists and Equal. Defaults to Equal. Exists is equivalent to wildcard for value, so that a pod can tolerate all taints of a particular category."
+                                    "type"        = "string"
+                                  }
+                                  "tolerationSeconds" = {
+                                    "description" = "TolerationSeconds represents the period of time the toleration (which must be of effect NoExecute, otherwise this field is ignored) tolerates the taint. By default, it is not set, which means tolerate the taint forever (do not evict). Zero and negative values will be treated as 0 (evict immediately) by the system."
+                                    "format"      = "int64"
+                                    "type"        = "integer"
+                                  }
+                                  "value" = {
+                                    "description" = "Value is the taint value the toleration matches to. If the operator is Exists, the value should be empty, otherwise just a regular string."
+                                    "type"        = "string"
+                                  }
+                                }
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "topologySpreadConstraints" = {
+                              "items" = {
+                                "description" = "TopologySpreadConstraint specifies how to spread matching pods among the given topology."
+                                "properties" = {
+                                  "labelSelector" = {
+                                    "description" = "LabelSelector is used to find matching pods. Pods that match this label selector are counted to determine the number of pods in their corresponding topology domain."
+                                    "properties" = {
+                                      "matchExpressions" = {
+                                        "description" = "matchExpressions is a list of label selector requirements. The requirements are ANDed."
+                                        "items" = {
+                                          "description" = "A label selector requirement is a selector that contains values, a key, and an operator that relates the key and values."
+                                          "properties" = {
+                                            "key" = {
+                                              "description" = "key is the label key that the selector applies to."
+                                              "type"        = "string"
+                                            }
+                                            "operator" = {
+                                              "description" = "operator represents a key's relationship to a set of values. Valid operators are In, NotIn, Exists and DoesNotExist."
+                                              "type"        = "string"
+                                            }
+                                            "values" = {
+                                              "description" = "values is an array of string values. If the operator is In or NotIn, the values array must be non-empty. If the operator is Exists or DoesNotExist, the values array must be empty. This array is replaced during a strategic merge patch."
+                                              "items" = {
+                                                "type" = "string"
+                                              }
+                                              "type" = "array"
+                                            }
+                                          }
+                                          "required" = [
+                                            "key",
+                                            "operator",
+                                          ]
+                                          "type" = "object"
+                                        }
+                                        "type" = "array"
+                                      }
+                                      "matchLabels" = {
+                                        "additionalProperties" = {
+                                          "type" = "string"
+                                        }
+                                        "description" = "matchLabels is a map of {key,value} pairs. A single {key,value} in the matchLabels map is equivalent to an element of matchExpressions, whose key field is \"key\", the operator is \"In\", and the values array contains only \"value\". The requirements are ANDed."
+                                        "type"        = "object"
+                                      }
+                                    }
+                                    "type" = "object"
+                                  }
+                                  "matchLabelKeys" = {
+                                    "description" = "MatchLabelKeys is a set of pod label keys to select the pods over which spreading will be calculated. The keys are used to lookup values from the incoming pod labels, those key-value labels are ANDed with labelSelector to select the group of existing pods over which spreading will be calculated for the incoming pod. The same key is forbidden to exist in both MatchLabelKeys and LabelSelector. MatchLabelKeys cannot be set when LabelSelector isn't set. Keys that don't exist in the incoming pod labels will be ignored. A null or empty list means only match against labelSelector.\n\nThis is a beta field and requires the MatchLabelKeysInPodTopologySpread feature gate to be enabled (enabled by default)."
+                                    "items" = {
+                                      "type" = "string"
+                                    }
+                                    "type" = "array"
+                                  }
+                                  "maxSkew" = {
+                                    "description" = "MaxSkew describes the degree to which pods may be unevenly distributed. When `whenUnsatisfiable=DoNotSchedule`, it is the maximum permitted difference between the number of matching pods in the target topology and the global minimum. The global minimum is the minimum number of matching pods in an eligible domain or zero if the number of eligible domains is less than MinDomains. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 2/2/1: In this case, the global minimum is 1. | zone1 | zone2 | zone3 | |  P P  |  P P  |   P   | - if MaxSkew is 1, incoming pod can only be scheduled to zone3 to become 2/2/2; scheduling it onto zone1(zone2) would make the ActualSkew(3-1) on zone1(zone2) violate MaxSkew(1). - if MaxSkew is 2, incoming pod can be scheduled onto any zone. When `whenUnsatisfiable=ScheduleAnyway`, it is used to give higher precedence to topologies that satisfy it. It's a required field. Default value is 1 and 0 is not allowed."
+                                    "format"      = "int32"
+                                    "type"        = "integer"
+                                  }
+                                  "minDomains" = {
+                                    "description" = "MinDomains indicates a minimum number of eligible domains. When the number of eligible domains with matching topology keys is less than minDomains, Pod Topology Spread treats \"global minimum\" as 0, and then the calculation of Skew is performed. And when the number of eligible domains with matching topology keys equals or greater than minDomains, this value has no effect on scheduling. As a result, when the number of eligible domains is less than minDomains, scheduler won't schedule more than maxSkew Pods to those domains. If value is nil, the constraint behaves as if MinDomains is equal to 1. Valid values are integers greater than 0. When value is not nil, WhenUnsatisfiable must be DoNotSchedule.\n\nFor example, in a 3-zone cluster, MaxSkew is set to 2, MinDomains is set to 5 and pods with the same labelSelector spread as 2/2/2: | zone1 | zone2 | zone3 | |  P P  |  P P  |  P P  | The number of domains is less than 5(MinDomains), so \"global minimum\" is treated as 0. In this situation, new pod with the same labelSelector cannot be scheduled, because computed skew will be 3(3 - 0) if new Pod is scheduled to any of the three zones, it will violate MaxSkew."
+                                    "format"      = "int32"
+                                    "type"        = "integer"
+                                  }
+                                  "nodeAffinityPolicy" = {
+                                    "description" = "NodeAffinityPolicy indicates how we will treat Pod's nodeAffinity/nodeSelector when calculating pod topology spread skew. Options are: - Honor: only nodes matching nodeAffinity/nodeSelector are included in the calculations. - Ignore: nodeAffinity/nodeSelector are ignored. All nodes are included in the calculations.\n\nIf this value is nil, the behavior is equivalent to the Honor policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                    "type"        = "string"
+                                  }
+                                  "nodeTaintsPolicy" = {
+                                    "description" = "NodeTaintsPolicy indicates how we will treat node taints when calculating pod topology spread skew. Options are: - Honor: nodes without taints, along with tainted nodes for which the incoming pod has a toleration, are included. - Ignore: node taints are ignored. All nodes are included.\n\nIf this value is nil, the behavior is equivalent to the Ignore policy. This is a beta-level feature default enabled by the NodeInclusionPolicyInPodTopologySpread feature flag."
+                                    "type"        = "string"
+                                  }
+                                  "topologyKey" = {
+                                    "description" = "TopologyKey is the key of node labels. Nodes that have a label with this key and identical values are considered to be in the same topology. We consider each <key, value> as a \"bucket\", and try to put balanced number of pods into each bucket. We define a domain as a particular instance of a topology. Also, we define an eligible domain as a domain whose nodes meet the requirements of nodeAffinityPolicy and nodeTaintsPolicy. e.g. If TopologyKey is \"kubernetes.io/hostname\", each Node is a domain of that topology. And, if TopologyKey is \"topology.kubernetes.io/zone\", each zone is a domain of that topology. It's a required field."
+                                    "type"        = "string"
+                                  }
+                                  "whenUnsatisfiable" = {
+                                    "description" = "WhenUnsatisfiable indicates how to deal with a pod if it doesn't satisfy the spread constraint. - DoNotSchedule (default) tells the scheduler not to schedule it. - ScheduleAnyway tells the scheduler to schedule the pod in any location,\n  but giving higher precedence to topologies that would help reduce the\n  skew.\nA constraint is considered \"Unsatisfiable\" for an incoming pod if and only if every possible node assignment for that pod would violate \"MaxSkew\" on some topology. For example, in a 3-zone cluster, MaxSkew is set to 1, and pods with the same labelSelector spread as 3/1/1: | zone1 | zone2 | zone3 | | P P P |   P   |   P   | If WhenUnsatisfiable is set to DoNotSchedule, incoming pod can only be scheduled to zone2(zone3) to become 3/2/1(3/1/2) as ActualSkew(2-1) on zone2(zone3) satisfies MaxSkew(1). In other words, the cluster can still be imbalanced, but scheduler won't make it *more* imbalanced. It's a required field."
+                                    "type"        = "string"
+                                  }
+                                }
+                                "required" = [
+                                  "maxSkew",
+                                  "topologyKey",
+                                  "whenUnsatisfiable",
+                                ]
+                                "type" = "object"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "securityContext" = {
+                          "description" = "Security settings applied to the node's pod and all of its containers. Anything left out keeps the defaults, which satisfy the Pod Security Admission \"restricted\" profile."
+                          "nullable"    = true
+                          "properties" = {
+                            "addCapabilities" = {
+                              "description" = "Capabilities added back after dropping `ALL`."
+                              "items" = {
+                                "type" = "string"
+                              }
+                              "nullable" = true
+                              "type"     = "array"
+                            }
+                            "allowPrivilegeEscalation" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "fsGroup" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "readOnlyRootFilesystem" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "runAsGroup" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "runAsNonRoot" = {
+                              "nullable" = true
+                              "type"     = "boolean"
+                            }
+                            "runAsUser" = {
+                              "format"   = "int64"
+                              "nullable" = true
+                              "type"     = "integer"
+                            }
+                            "seccompLocalhostProfile" = {
+                              "description" = "Profile path, only used with `Localhost`."
+                              "nullable"    = true
+                              "type"        = "string"
+                            }
+                            "seccompProfile" = {
+                              "description" = "`RuntimeDefault`, `Unconfined` or `Localhost`."
+                              "nullable"    = true
+                              "type"        = "string"
+                            }
+                          }
+                          "type" = "object"
+                        }
+                        "seedInput" = {
+                          "type" = "string"
+                        }
+                        "startChainFrom" = {
+                          "nullable" = true
+                          "type"     = "string"
+                        }
+                      }
+                      "required" = [
+                        "commitInputs",
+                        "seedInput",
+                      ]
+                      "type" = "object"
+                    }
+                    "openedAt" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "timeToOpenSeconds" = {
+                      "format"   = "int64"
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
+                    "totalTransactions" = {
+                      "format" = "int64"
+                      "type"   = "integer"
+                    }
+                    "transitions" = {
+                      "items" = {
+                        "properties" = {
+                          "at" = {
+                            "type" = "string"
+                          }
+                          "state" = {
+                            "type" = "string"
+                          }
+                        }
+                        "required" = [
+                          "at",
+                          "state",
+                        ]
+                        "type" = "object"
+                      }
+                      "type" = "array"
+                    }
+                    "uptimeSeconds" = {
+                      "format"  = "uint64"
+                      "minimum" = 0
+                      "type"    = "integer"
+                    }
+                  }
+                  "required" = [
+                    "node",
+                    "nodeSpec",
+                    "totalTransactions",
+                    "transitions",
+                    "uptimeSeconds",
+                  ]
+                  "type" = "object"
+                  "x-kubernetes-validations" = [
+                    {
+                      "message" = "Reports are immutable"
+                      "rule"    = "self == oldSelf"
+                    },
+                  ]
+                }
+              }
+              "required" = [
+                "spec",
+              ]
+              "title" = "HydraDoomNodeReport"
+              "type"  = "object"
+            }
+          }
+          "served"       = true
+          "storage"      = true
+          "subresources" = {}
+        },
+      ]
+    }
+  }
+}
+
+resource "kubernetes_manifest" "customresourcedefinition_hydradoomconfigs_hydra_doom" {
+  manifest = {
+    "apiVersion" = "apiextensions.k8s.io/v1"
+    "kind"       = "CustomResourceDefinition"
+    "metadata" = {
+      "name" = "hydradoomconfigs.hydra.doom"
+    }
+    "spec" = {
+      "group" = "hydra.doom"
+      "names" = {
+        "categories" = [
+          "hydradoom",
+        ]
+        "kind"   = "HydraDoomConfig"
+        "plural" = "hydradoomconfigs"
+        "shortNames" = [
+          "hydradoomconfig",
+        ]
+        "singular" = "hydradoomconfig"
+      }
+      "scope" = "Namespaced"
+      "versions" = [
+        {
+          "additionalPrinterColumns" = [
+            {
+              "jsonPath" = ".status.conditions[?(@.type==\"Valid\")].status"
+              "name"     = "Valid"
+              "type"     = "string"
+            },
+          ]
+          "name" = "v1alpha1"
+          "schema" = {
+            "openAPIV3Schema" = {
+              "description" = "Auto-generated derived type for HydraDoomConfigSpec via `CustomResource`"
+              "properties" = {
+                "spec" = {
+                  "description" = "Operator settings that can be changed without redeploying the operator. Every field given here overrides the corresponding env var and the `ClusterHydraDoomConfig`; the operator only reads the one named by `HYDRA_DOOM_CONFIG` in its own namespace."
+                  "properties" = {
+                    "adminAddr" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "blockfrostKeyRef" = {
+                      "description" = "Reference to a key of a Secret in the nodes' namespace, given in env vars as `<secret name>:<key>`."
+                      "nullable"    = true
+                      "properties" = {
+                        "key" = {
+                          "type" = "string"
+                        }
+                        "name" = {
+                          "type" = "string"
+                        }
+                      }
+                      "required" = [
+                        "key",
+                        "name",
+                      ]
+                      "type" = "object"
+                    }
+                    "configmap" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "dmtrApiKeyRef" = {
+                      "description" = "Reference to a key of a Secret in the nodes' namespace, given in env vars as `<secret name>:<key>`."
+                      "nullable"    = true
+                      "properties" = {
+                        "key" = {
+                          "type" = "string"
+                        }
+                        "name" = {
+                          "type" = "string"
+                        }
+                      }
+                      "required" = [
+                        "key",
+                        "name",
+                      ]
+                      "type" = "object"
+                    }
+                    "dmtrPortName" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "dmtrProjectId" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "externalDomain" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "externalPort" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "hydraScriptsTxId" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "image" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "openHeadImage" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "secret" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "sidecarImage" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                  }
+                  "type" = "object"
+                }
+                "status" = {
+                  "nullable" = true
+                  "properties" = {
+                    "conditions" = {
+                      "items" = {
+                        "description" = "Condition contains details for one aspect of the current state of this API Resource."
+                        "properties" = {
+                          "lastTransitionTime" = {
+                            "description" = "lastTransitionTime is the last time the condition transitioned from one status to another. This should be when the underlying condition changed.  If that is not known, then using the time when the API field changed is acceptable."
+                            "format"      = "date-time"
+                            "type"        = "string"
+                          }
+                          "message" = {
+                            "description" = "message is a human readable message indicating details about the transition. This may be an empty string."
+                            "type"        = "string"
+                          }
+                          "observedGeneration" = {
+                            "description" = "observedGeneration represents the .metadata.generation that the condition was set based upon. For instance, if .metadata.generation is currently 12, but the .status.conditions[x].observedGeneration is 9, the condition is out of date with respect to the current state of the instance."
+                            "format"      = "int64"
+                            "type"        = "integer"
+                          }
+                          "reason" = {
+                            "description" = "reason contains a programmatic identifier indicating the reason for the condition's last transition. Producers of specific condition types may define expected values and meanings for this field, and whether the values are considered a guaranteed API. The value should be a CamelCase string. This field may not be empty."
+                            "type"        = "string"
+                          }
+                          "status" = {
+                            "description" = "status of the condition, one of True, False, Unknown."
+                            "type"        = "string"
+                          }
+                          "type" = {
+                            "description" = "type of condition in CamelCase or in foo.example.com/CamelCase."
+                            "type"        = "string"
+                          }
+                        }
+                        "required" = [
+                          "lastTransitionTime",
+                          "message",
+                          "reason",
+                          "status",
+                          "type",
+                        ]
+                        "type" = "object"
+                      }
+                      "type" = "array"
+                    }
+                    "observedGeneration" = {
+                      "format"   = "int64"
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
+                  }
+                  "required" = [
+                    "conditions",
+                  ]
+                  "type" = "object"
+                }
+              }
+              "required" = [
+                "spec",
+              ]
+              "title" = "HydraDoomConfig"
+              "type"  = "object"
+            }
+          }
+          "served"  = true
+          "storage" = true
+          "subresources" = {
+            "status" = {}
+          }
+        },
+      ]
+    }
+  }
+}
+
+resource "kubernetes_manifest" "customresourcedefinition_clusterhydradoomconfigs_hydra_doom" {
+  manifest = {
+    "apiVersion" = "apiextensions.k8s.io/v1"
+    "kind"       = "CustomResourceDefinition"
+    "metadata" = {
+      "name" = "clusterhydradoomconfigs.hydra.doom"
+    }
+    "spec" = {
+      "group" = "hydra.doom"
+      "names" = {
+        "categories" = [
+          "hydradoom",
+        ]
+        "kind"   = "ClusterHydraDoomConfig"
+        "plural" = "clusterhydradoomconfigs"
+        "shortNames" = [
+          "clusterhydradoomconfig",
+        ]
+        "singular" = "clusterhydradoomconfig"
+      }
+      "scope" = "Cluster"
+      "versions" = [
+        {
+          "additionalPrinterColumns" = [
+            {
+              "jsonPath" = ".status.conditions[?(@.type==\"Valid\")].status"
+              "name"     = "Valid"
+              "type"     = "string"
+            },
+          ]
+          "name" = "v1alpha1"
+          "schema" = {
+            "openAPIV3Schema" = {
+              "description" = "Auto-generated derived type for ClusterHydraDoomConfigSpec via `CustomResource`"
+              "properties" = {
+                "spec" = {
+                  "description" = "Cluster-wide version of `HydraDoomConfig`, shared by operators in several namespaces. Its settings override the env vars and are in turn overridden by the namespaced one; only the one named by `HYDRA_DOOM_CONFIG` is read."
+                  "properties" = {
+                    "adminAddr" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "blockfrostKeyRef" = {
+                      "description" = "Reference to a key of a Secret in the nodes' namespace, given in env vars as `<secret name>:<key>`."
+                      "nullable"    = true
+                      "properties" = {
+                        "key" = {
+                          "type" = "string"
+                        }
+                        "name" = {
+                          "type" = "string"
+                        }
+                      }
+                      "required" = [
+                        "key",
+                        "name",
+                      ]
+                      "type" = "object"
+                    }
+                    "configmap" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "dmtrApiKeyRef" = {
+                      "description" = "Reference to a key of a Secret in the nodes' namespace, given in env vars as `<secret name>:<key>`."
+                      "nullable"    = true
+                      "properties" = {
+                        "key" = {
+                          "type" = "string"
+                        }
+                        "name" = {
+                          "type" = "string"
+                        }
+                      }
+                      "required" = [
+                        "key",
+                        "name",
+                      ]
+                      "type" = "object"
+                    }
+                    "dmtrPortName" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "dmtrProjectId" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "externalDomain" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "externalPort" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "hydraScriptsTxId" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "image" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "openHeadImage" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "secret" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                    "sidecarImage" = {
+                      "nullable" = true
+                      "type"     = "string"
+                    }
+                  }
+                  "type" = "object"
+                }
+                "status" = {
+                  "nullable" = true
+                  "properties" = {
+                    "conditions" = {
+                      "items" = {
+                        "description" = "Condition contains details for one aspect of the current state of this API Resource."
+                        "properties" = {
+                          "lastTransitionTime" = {
+                            "description" = "lastTransitionTime is the last time the condition transitioned from one status to another. This should be when the underlying condition changed.  If that is not known, then using the time when the API field changed is acceptable."
+                            "format"      = "date-time"
+                            "type"        = "string"
+                          }
+                          "message" = {
+                            "description" = "message is a human readable message indicating details about the transition. This may be an empty string."
+                            "type"        = "string"
+                          }
+                          "observedGeneration" = {
+                            "description" = "observedGeneration represents the .metadata.generation that the condition was set based upon. For instance, if .metadata.generation is currently 12, but the .status.conditions[x].observedGeneration is 9, the condition is out of date with respect to the current state of the instance."
+                            "format"      = "int64"
+                            "type"        = "integer"
+                          }
+                          "reason" = {
+                            "description" = "reason contains a programmatic identifier indicating the reason for the condition's last transition. Producers of specific condition types may define expected values and meanings for this field, and whether the values are considered a guaranteed API. The value should be a CamelCase string. This field may not be empty."
+                            "type"        = "string"
+                          }
+                          "status" = {
+                            "description" = "status of the condition, one of True, False, Unknown."
+                            "type"        = "string"
+                          }
+                          "type" = {
+                            "description" = "type of condition in CamelCase or in foo.example.com/CamelCase."
+                            "type"        = "string"
+                          }
+                        }
+                        "required" = [
+                          "lastTransitionTime",
+                          "message",
+                          "reason",
+                          "status",
+                          "type",
+                        ]
+                        "type" = "object"
+                      }
+                      "type" = "array"
+                    }
+                    "observedGeneration" = {
+                      "format"   = "int64"
+                      "nullable" = true
+                      "type"     = "integer"
+                    }
+                  }
+                  "required" = [
+                    "conditions",
+                  ]
+                  "type" = "object"
+                }
+              }
+              "required" = [
+                "spec",
+              ]
+              "title" = "ClusterHydraDoomConfig"
+              "type"  = "object"
+            }
+          }
+          "served"  = true
+          "storage" = true
+          "subresources" = {
+            "status" = {}
+          }
+        },
+      ]
+    }
+  }
+}
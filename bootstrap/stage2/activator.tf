@@ -0,0 +1,216 @@
+// Wake-on-connect activator. Sleeping nodes' ingresses route here; only
+// deployed when an image is given. Build it with `--build-arg BIN=activator`.
+resource "kubernetes_service_account_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    namespace = var.namespace
+    name      = local.activator_component
+  }
+}
+
+resource "kubernetes_role_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    namespace = var.namespace
+    name      = local.activator_component
+  }
+
+  rule {
+    api_groups = ["hydra.doom"]
+    resources  = ["hydradoomnodes"]
+    verbs      = ["get", "list", "watch", "patch"]
+  }
+
+  rule {
+    api_groups = ["hydra.doom"]
+    resources  = ["hydradoomconfigs"]
+    verbs      = ["get", "list", "watch"]
+  }
+}
+
+resource "kubernetes_role_binding_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    namespace = var.namespace
+    name      = local.activator_component
+  }
+  role_ref {
+    api_group = "rbac.authorization.k8s.io"
+    kind      = "Role"
+    name      = local.activator_component
+  }
+  subject {
+    kind      = "ServiceAccount"
+    name      = local.activator_component
+    namespace = var.namespace
+  }
+}
+
+// The cluster-wide config is read too, which a Role cannot grant.
+resource "kubernetes_cluster_role_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    name = "${var.namespace}-${local.activator_component}"
+  }
+
+  rule {
+    api_groups = ["hydra.doom"]
+    resources  = ["clusterhydradoomconfigs"]
+    verbs      = ["get", "list", "watch"]
+  }
+}
+
+resource "kubernetes_cluster_role_binding_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    name = "${var.namespace}-${local.activator_component}"
+  }
+  role_ref {
+    api_group = "rbac.authorization.k8s.io"
+    kind      = "ClusterRole"
+    name      = "${var.namespace}-${local.activator_component}"
+  }
+  subject {
+    kind      = "ServiceAccount"
+    name      = local.activator_component
+    namespace = var.namespace
+  }
+}
+
+resource "kubernetes_deployment_v1" "activator" {
+  count            = var.activator_image == null ? 0 : 1
+  wait_for_rollout = false
+
+  metadata {
+    namespace = var.namespace
+    name      = local.activator_component
+    labels = {
+      role = local.activator_component
+    }
+  }
+
+  spec {
+    replicas = 1
+
+    selector {
+      match_labels = {
+        role = local.activator_component
+      }
+    }
+
+    template {
+      metadata {
+        labels = {
+          role = local.activator_component
+        }
+      }
+
+      spec {
+        service_account_name = local.activator_component
+
+        container {
+          image = var.activator_image
+          name  = "main"
+
+          // Only what is needed to match hosts to nodes; everything else
+          // keeps its default or comes from the HydraDoomConfig.
+          env {
+            name = "POD_NAMESPACE"
+            value_from {
+              field_ref {
+                field_path = "metadata.namespace"
+              }
+            }
+          }
+
+          env {
+            name  = "EXTERNAL_DOMAIN"
+            value = var.external_domain
+          }
+
+          env {
+            name  = "EXTERNAL_PORT"
+            value = var.external_port
+          }
+
+          dynamic "env" {
+            for_each = local.routing_env
+
+            content {
+              name  = env.key
+              value = env.value
+            }
+          }
+
+          env {
+            name  = "ACTIVATOR_PORT"
+            value = local.activator_port
+          }
+
+          resources {
+            limits = {
+              cpu    = "500m"
+              memory = "128Mi"
+            }
+            requests = {
+              cpu    = "50m"
+              memory = "64Mi"
+            }
+          }
+
+          port {
+            name           = "http"
+            container_port = local.activator_port
+            protocol       = "TCP"
+          }
+
+          readiness_probe {
+            tcp_socket {
+              port = local.activator_port
+            }
+            period_seconds = 5
+          }
+        }
+
+        dynamic "toleration" {
+          for_each = var.tolerations
+
+          content {
+            effect   = toleration.value.effect
+            key      = toleration.value.key
+            operator = toleration.value.operator
+            value    = toleration.value.value
+          }
+        }
+      }
+    }
+  }
+}
+
+resource "kubernetes_service_v1" "activator" {
+  count = var.activator_image == null ? 0 : 1
+
+  metadata {
+    name      = local.activator_component
+    namespace = var.namespace
+  }
+
+  spec {
+    type = "ClusterIP"
+
+    selector = {
+      role = local.activator_component
+    }
+
+    port {
+      name        = "http"
+      port        = local.activator_port
+      target_port = local.activator_port
+    }
+  }
+}
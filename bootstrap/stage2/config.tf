@@ -2,6 +2,9 @@ resource "kubernetes_config_map" "node-config" {
   metadata {
     namespace = var.namespace
     name      = local.configmap
+    labels = {
+      "hydra.doom/roll-nodes" = "true"
+    }
   }
 
   data = {
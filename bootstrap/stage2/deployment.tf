@@ -66,8 +66,17 @@ resource "kubernetes_deployment_v1" "operator" {
           }
 
           env {
-            name  = "BLOCKFROST_KEY"
-            value = var.blockfrost_key
+            name  = "BLOCKFROST_KEY_SECRET"
+            value = "${local.credentials_secret}:blockfrost-key"
+          }
+
+          env {
+            name = "POD_NAMESPACE"
+            value_from {
+              field_ref {
+                field_path = "metadata.namespace"
+              }
+            }
           }
 
           env {
@@ -80,6 +89,15 @@ resource "kubernetes_deployment_v1" "operator" {
             value = var.external_port
           }
 
+          dynamic "env" {
+            for_each = local.routing_env
+
+            content {
+              name  = env.key
+              value = env.value
+            }
+          }
+
           env {
             name  = "ADMIN_ADDR"
             value = var.admin_addr
@@ -96,8 +114,8 @@ resource "kubernetes_deployment_v1" "operator" {
           }
 
           env {
-            name  = "DMTR_API_KEY"
-            value = var.dmtr_api_key
+            name  = "DMTR_API_KEY_SECRET"
+            value = "${local.credentials_secret}:dmtr-api-key"
           }
 
           env {
@@ -105,6 +123,33 @@ resource "kubernetes_deployment_v1" "operator" {
             value = var.dmtr_port_name
           }
 
+          dynamic "env" {
+            for_each = var.activator_image == null ? [] : [local.activator_component]
+
+            content {
+              name  = "ACTIVATOR_SERVICE"
+              value = env.value
+            }
+          }
+
+          env {
+            name  = "ACTIVATOR_PORT"
+            value = local.activator_port
+          }
+
+          dynamic "env" {
+            for_each = var.webhook_enabled ? {
+              WEBHOOK_ADDR      = "0.0.0.0:${local.webhook_port}"
+              WEBHOOK_CERT_FILE = "${local.webhook_cert_dir}/tls.crt"
+              WEBHOOK_KEY_FILE  = "${local.webhook_cert_dir}/tls.key"
+            } : {}
+
+            content {
+              name  = env.key
+              value = env.value
+            }
+          }
+
           resources {
             limits = {
               cpu    = var.resources.limits.cpu
@@ -121,6 +166,53 @@ resource "kubernetes_deployment_v1" "operator" {
             container_port = 8000
             protocol       = "TCP"
           }
+
+          port {
+            name           = "health"
+            container_port = 8082
+            protocol       = "TCP"
+          }
+
+          port {
+            name           = "metrics"
+            container_port = 9090
+            protocol       = "TCP"
+          }
+
+          port {
+            name           = "webhook"
+            container_port = local.webhook_port
+            protocol       = "TCP"
+          }
+
+          dynamic "volume_mount" {
+            for_each = var.webhook_enabled ? [local.webhook_cert_dir] : []
+
+            content {
+              name       = "webhook-tls"
+              mount_path = volume_mount.value
+              read_only  = true
+            }
+          }
+
+          liveness_probe {
+            http_get {
+              path = "/healthz"
+              port = "health"
+            }
+            period_seconds    = 10
+            failure_threshold = 3
+          }
+
+          // Ready once the node cache has synced.
+          readiness_probe {
+            http_get {
+              path = "/readyz"
+              port = "health"
+            }
+            period_seconds    = 5
+            failure_threshold = 3
+          }
         }
 
         volume {
@@ -130,6 +222,17 @@ resource "kubernetes_deployment_v1" "operator" {
           }
         }
 
+        dynamic "volume" {
+          for_each = var.webhook_enabled ? [local.webhook_secret] : []
+
+          content {
+            name = "webhook-tls"
+            secret {
+              secret_name = volume.value
+            }
+          }
+        }
+
         dynamic "toleration" {
           for_each = var.tolerations
 
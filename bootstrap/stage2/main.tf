@@ -2,8 +2,24 @@ locals {
   operator_component      = "operator"
   configmap               = "hydra-pod-config"
   secret                  = "hydra-pod-admin-key"
+  credentials_secret      = "hydra-pod-credentials"
   secret_mount_path       = "/var/secret"
   control_plane_component = "control-plane"
+  activator_component     = "activator"
+  activator_port          = 8080
+  webhook_component       = "webhook"
+  webhook_port            = 8443
+  webhook_secret          = "hydra-doom-webhook-tls"
+  webhook_cert_dir        = "/etc/webhook"
+
+  // Shared by the operator and the activator, which have to agree on the
+  // host and path every node is served under.
+  routing_env = {
+    for name, value in {
+      EXTERNAL_HOST_TEMPLATE = var.external_host_template
+      EXTERNAL_PATH_TEMPLATE = var.external_path_template
+    } : name => value if value != null
+  }
 }
 
 variable "namespace" {
@@ -28,6 +44,18 @@ variable "sidecar_image" {
   type = string
 }
 
+variable "activator_image" {
+  type        = string
+  default     = null
+  description = "Operator image built with BIN=activator. Sleeping nodes are only woken on connect when set."
+}
+
+variable "webhook_enabled" {
+  type        = bool
+  default     = false
+  description = "Validate HydraDoomNode overrides on admission. Requires cert-manager."
+}
+
 variable "control_plane_image" {
   type = string
 }
@@ -49,6 +77,18 @@ variable "external_port" {
   type = number
 }
 
+variable "external_host_template" {
+  type        = string
+  default     = null
+  description = "Host nodes are served under, with {name}, {namespace} and {domain} placeholders. Defaults to {name}.{domain}."
+}
+
+variable "external_path_template" {
+  type        = string
+  default     = null
+  description = "Path prefix nodes are served under, with the same placeholders. Nodes get a host each when unset."
+}
+
 variable "admin_key" {
   type        = string
   description = "The admin key in cardano-cli JSON format."
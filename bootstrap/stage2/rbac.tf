@@ -21,9 +21,33 @@ resource "kubernetes_cluster_role" "cluster_role" {
     verbs      = ["*"]
   }
 
+  rule {
+    api_groups = [""]
+    resources  = ["secrets"]
+    verbs      = ["*"]
+  }
+
+  rule {
+    api_groups = [""]
+    resources  = ["pods", "nodes"]
+    verbs      = ["get", "list", "watch"]
+  }
+
+  rule {
+    api_groups = ["policy"]
+    resources  = ["poddisruptionbudgets"]
+    verbs      = ["*"]
+  }
+
   rule {
     api_groups = ["networking.k8s.io"]
-    resources  = ["ingresses"]
+    resources  = ["ingresses", "networkpolicies"]
+    verbs      = ["*"]
+  }
+
+  rule {
+    api_groups = ["gateway.networking.k8s.io"]
+    resources  = ["httproutes"]
     verbs      = ["*"]
   }
 
@@ -2,9 +2,27 @@ resource "kubernetes_secret" "admin_key" {
   metadata {
     name      = local.secret
     namespace = var.namespace
+    labels = {
+      "hydra.doom/roll-nodes" = "true"
+    }
   }
   data = {
     "admin.sk" = var.admin_key
   }
   type = "Opaque"
 }
+
+resource "kubernetes_secret" "credentials" {
+  metadata {
+    name      = local.credentials_secret
+    namespace = var.namespace
+    labels = {
+      "hydra.doom/roll-nodes" = "true"
+    }
+  }
+  data = {
+    "blockfrost-key" = var.blockfrost_key
+    "dmtr-api-key"   = var.dmtr_api_key
+  }
+  type = "Opaque"
+}
@@ -0,0 +1,123 @@
+// Validating webhook rejecting HydraDoomNodes whose overrides do not apply.
+// Only deployed when enabled; needs cert-manager, which issues the serving
+// certificate and injects its CA into the webhook configuration.
+resource "kubernetes_manifest" "webhook_issuer" {
+  count = var.webhook_enabled ? 1 : 0
+
+  manifest = {
+    apiVersion = "cert-manager.io/v1"
+    kind       = "Issuer"
+    metadata = {
+      name      = local.webhook_component
+      namespace = var.namespace
+    }
+    spec = {
+      selfSigned = {}
+    }
+  }
+}
+
+resource "kubernetes_manifest" "webhook_certificate" {
+  count = var.webhook_enabled ? 1 : 0
+
+  manifest = {
+    apiVersion = "cert-manager.io/v1"
+    kind       = "Certificate"
+    metadata = {
+      name      = local.webhook_component
+      namespace = var.namespace
+    }
+    spec = {
+      secretName = local.webhook_secret
+      // The operator only reads the certificate at startup, so it should not
+      // need renewing while a pod is running.
+      duration = "87600h"
+      dnsNames = [
+        "${local.webhook_component}.${var.namespace}.svc",
+        "${local.webhook_component}.${var.namespace}.svc.cluster.local",
+      ]
+      // The operator only reads PKCS #8 keys.
+      privateKey = {
+        algorithm = "ECDSA"
+        encoding  = "PKCS8"
+      }
+      issuerRef = {
+        kind = "Issuer"
+        name = local.webhook_component
+      }
+    }
+  }
+}
+
+resource "kubernetes_service_v1" "webhook" {
+  count = var.webhook_enabled ? 1 : 0
+
+  metadata {
+    name      = local.webhook_component
+    namespace = var.namespace
+  }
+
+  spec {
+    type = "ClusterIP"
+
+    selector = {
+      role = local.operator_component
+    }
+
+    port {
+      name        = "https"
+      port        = 443
+      target_port = local.webhook_port
+    }
+  }
+}
+
+resource "kubernetes_validating_webhook_configuration_v1" "webhook" {
+  count = var.webhook_enabled ? 1 : 0
+
+  metadata {
+    name = "${var.namespace}-hydradoomnodes"
+    annotations = {
+      "cert-manager.io/inject-ca-from" = "${var.namespace}/${local.webhook_component}"
+    }
+  }
+
+  webhook {
+    name                      = "hydradoomnodes.hydra.doom"
+    admission_review_versions = ["v1"]
+    side_effects              = "None"
+    # The webhook is served by the single operator replica, so it is
+    # unavailable during rollouts. A bad override admitted meanwhile still
+    # fails the node's reconcile.
+    failure_policy  = "Ignore"
+    timeout_seconds = 10
+
+    client_config {
+      service {
+        namespace = var.namespace
+        name      = local.webhook_component
+        path      = "/validate"
+        port      = 443
+      }
+    }
+
+    rule {
+      api_groups   = ["hydra.doom"]
+      api_versions = ["v1alpha1"]
+      operations   = ["CREATE", "UPDATE"]
+      resources    = ["hydradoomnodes"]
+      scope        = "Namespaced"
+    }
+
+    namespace_selector {
+      match_labels = {
+        "kubernetes.io/metadata.name" = var.namespace
+      }
+    }
+  }
+
+  // The CA bundle is filled in by cert-manager.
+  lifecycle {
+    ignore_changes = [webhook[0].client_config[0].ca_bundle]
+  }
+}
@@ -0,0 +1,232 @@
+//! Wake-on-connect activator.
+//!
+//! While a node is asleep its Ingress routes to this service instead of the
+//! node's own Service (see `HydraDoomNode::ingress`). For every incoming
+//! connection the activator finds the node by host (and path prefix), flips
+//! `spec.asleep` to false, waits until the status loop reports it as Online or
+//! HeadIsOpen and then proxies the raw connection (websocket upgrade included)
+//! to the node.
+use anyhow::bail;
+use futures::StreamExt;
+use kube::{
+    api::{Patch, PatchParams},
+    runtime::{
+        reflector::{self, store::Writer, ObjectRef, Store},
+        watcher, WatchStreamExt,
+    },
+    Api, Client, ResourceExt,
+};
+use serde_json::json;
+use std::{
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use thiserror::Error;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpListener, TcpStream},
+};
+use tracing::{error, info, warn};
+
+use crate::{
+    config::Config,
+    controller::{HydraDoomNodeState, K8sConstants},
+    custom_resource::HydraDoomNode,
+    http::{read_request_head, respond},
+};
+
+#[derive(Debug, Error)]
+enum WakeError {
+    #[error("failed to wake up node: {0}")]
+    Api(#[from] kube::Error),
+    #[error("timed out waiting for {0} to wake up")]
+    Timeout(String),
+}
+
+/// Keeps `writer`'s store in sync with the nodes in the namespace, so that
+/// connections do not have to list them.
+pub async fn watch_nodes(client: Client, writer: Writer<HydraDoomNode>) {
+    let api: Api<HydraDoomNode> = Api::default_namespaced(client);
+    reflector::reflector(writer, watcher(api, watcher::Config::default()))
+        .default_backoff()
+        .touched_objects()
+        .for_each(|event| async move {
+            if let Err(err) = event {
+                warn!(err = err.to_string(), "Node watcher error.");
+            }
+        })
+        .await
+}
+
+pub struct Activator {
+    pub client: Client,
+    /// Swapped by `watch_config` whenever the config resources change.
+    config: RwLock<Arc<Config>>,
+    pub constants: K8sConstants,
+    pub store: Store<HydraDoomNode>,
+    pub wake_timeout: Duration,
+    pub poll_interval: Duration,
+}
+
+impl Activator {
+    pub fn new(
+        client: Client,
+        config: Config,
+        constants: K8sConstants,
+        store: Store<HydraDoomNode>,
+    ) -> Self {
+        Self {
+            client,
+            config: RwLock::new(Arc::new(config)),
+            constants,
+            store,
+            wake_timeout: Duration::from_secs(300),
+            poll_interval: Duration::from_secs(1),
+        }
+    }
+
+    pub fn config(&self) -> Arc<Config> {
+        self.config.read().unwrap().clone()
+    }
+
+    pub fn set_config(&self, config: Config) {
+        *self.config.write().unwrap() = Arc::new(config);
+    }
+
+    pub async fn run(self: Arc<Self>, addr: &str) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Activator listening on {}.", addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let activator = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = activator.handle(stream).await {
+                    warn!(
+                        err = err.to_string(),
+                        "Activator connection from {} failed.", peer
+                    );
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, mut stream: TcpStream) -> anyhow::Result<()> {
+        let head = read_request_head(&mut stream).await?;
+        let Some(host) = head.host() else {
+            respond(
+                &mut stream,
+                400,
+                "Bad Request",
+                "text/plain",
+                "Missing Host header",
+            )
+            .await?;
+            bail!("Request without Host header");
+        };
+
+        let Some(crd) = self.find_node(host, &head.path).await else {
+            respond(&mut stream, 404, "Not Found", "text/plain", "Unknown node").await?;
+            bail!("No node found for host {}", host);
+        };
+
+        let crd = match self.wake(&crd).await {
+            Ok(crd) => crd,
+            Err(err) => {
+                let (status, reason, body) = match err {
+                    WakeError::Api(_) => (503, "Service Unavailable", "Node could not be woken up"),
+                    WakeError::Timeout(_) => {
+                        (504, "Gateway Timeout", "Node did not wake up in time")
+                    }
+                };
+                respond(&mut stream, status, reason, "text/plain", body).await?;
+                return Err(err.into());
+            }
+        };
+
+        let mut upstream =
+            match TcpStream::connect((crd.internal_host(), self.constants.port as u16)).await {
+                Ok(upstream) => upstream,
+                Err(err) => {
+                    respond(
+                        &mut stream,
+                        502,
+                        "Bad Gateway",
+                        "text/plain",
+                        "Node is not reachable",
+                    )
+                    .await?;
+                    return Err(err.into());
+                }
+            };
+        // With path-based routing the prefix reaches us untouched and has to be
+        // stripped, just like the node's own route would have done.
+        match crd.external_path(&self.config()) {
+            Some(prefix) => {
+                let path = head.path.strip_prefix(&prefix).unwrap_or(&head.path);
+                let path = if path.starts_with('/') {
+                    path.to_string()
+                } else {
+                    format!("/{}", path)
+                };
+                upstream.write_all(&head.with_path(&path)).await?;
+            }
+            None => upstream.write_all(&head.raw).await?,
+        }
+        tokio::io::copy_bidirectional(&mut stream, &mut upstream).await?;
+
+        Ok(())
+    }
+
+    async fn find_node(&self, host: &str, path: &str) -> Option<Arc<HydraDoomNode>> {
+        // Before the first list completes every node would look unknown.
+        let _ = self.store.wait_until_ready().await;
+
+        let config = self.config();
+        self.store
+            .state()
+            .into_iter()
+            .find(|crd| crd.serves(host, path, &config, &self.constants))
+    }
+
+    async fn wake(&self, crd: &HydraDoomNode) -> Result<Arc<HydraDoomNode>, WakeError> {
+        let name = crd.name_any();
+        let api: Api<HydraDoomNode> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+
+        if crd.spec.asleep.unwrap_or(false) {
+            info!("Waking up {}.", name);
+            api.patch(
+                &name,
+                &PatchParams::default(),
+                &Patch::Merge(json!({ "spec": { "asleep": false } })),
+            )
+            .await
+            .inspect_err(|err| {
+                error!(err = err.to_string(), "Failed to wake up {}.", name);
+            })?;
+        }
+
+        // The status loop writes the new state to the resource, which the
+        // store picks up.
+        let wait = async {
+            loop {
+                let crd = self.store.get(&ObjectRef::from_obj(crd));
+                if let Some(crd) = crd.filter(|crd| {
+                    let ready = crd.status.as_ref().is_some_and(|status| {
+                        status.state == String::from(HydraDoomNodeState::Online)
+                            || status.state == String::from(HydraDoomNodeState::HeadIsOpen)
+                    });
+                    ready && !crd.spec.asleep.unwrap_or(false)
+                }) {
+                    return crd;
+                }
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        };
+
+        tokio::time::timeout(self.wake_timeout, wait)
+            .await
+            .map_err(|_| WakeError::Timeout(name))
+    }
+}
@@ -0,0 +1,168 @@
+//! Per-node websocket access tokens.
+//!
+//! The operator keeps a random token per node in a Secret owned by the node
+//! and points the node's Ingress at this endpoint through nginx's `auth-url`
+//! annotation. nginx calls it for every new connection with the original URL
+//! in `X-Original-URL`, which identifies the node; clients pass the token as a
+//! `token` query parameter (browsers cannot set headers on websockets) or as
+//! a bearer token.
+use k8s_openapi::api::core::v1::Secret;
+use kube::{runtime::reflector::Store, Api, Resource, ResourceExt};
+use rand::{distributions::Alphanumeric, Rng};
+use std::sync::Arc;
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{info, warn};
+
+use crate::{
+    controller::K8sContext,
+    custom_resource::HydraDoomNode,
+    http::{query_param, read_request_head, respond},
+};
+
+pub static TOKEN_KEY: &str = "token";
+pub static ROTATE_TOKEN_ANNOTATION: &str = "hydra.doom/rotate-token";
+pub static ROTATED_FOR_ANNOTATION: &str = "hydra.doom/rotated-for";
+
+pub fn generate_token() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(48)
+        .map(char::from)
+        .collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Host (without port) and path of an absolute URL.
+fn split_url(url: &str) -> Option<(&str, &str)> {
+    let (_, rest) = url.split_once("://")?;
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => rest.split_at(pos),
+        None => (rest, "/"),
+    };
+    Some((authority.split(':').next().unwrap_or(authority), path))
+}
+
+pub struct AuthServer {
+    context: Arc<K8sContext>,
+    store: Store<HydraDoomNode>,
+}
+
+impl AuthServer {
+    pub fn new(context: Arc<K8sContext>, store: Store<HydraDoomNode>) -> Self {
+        Self { context, store }
+    }
+
+    pub async fn run(self: Arc<Self>, addr: &str) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Auth endpoint listening on {}.", addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = server.handle(stream).await {
+                    warn!(err = err.to_string(), "Auth request from {} failed.", peer);
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, mut stream: TcpStream) -> anyhow::Result<()> {
+        let head = read_request_head(&mut stream).await?;
+
+        let Some((host, path)) = head.header("x-original-url").and_then(split_url) else {
+            return respond(&mut stream, 400, "Bad Request", "text/plain", "").await;
+        };
+        let token = query_param(path, TOKEN_KEY).or_else(|| {
+            head.header("authorization")
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .map(|token| token.to_string())
+        });
+
+        let expected = match self.find_node(host, path) {
+            Some(crd) => self.expected_token(&crd).await?,
+            None => None,
+        };
+
+        match (token, expected) {
+            (Some(token), Some(expected)) if constant_time_eq(token.as_bytes(), &expected) => {
+                respond(&mut stream, 200, "OK", "text/plain", "").await
+            }
+            _ => respond(&mut stream, 401, "Unauthorized", "text/plain", "").await,
+        }
+    }
+
+    fn find_node(&self, host: &str, path: &str) -> Option<Arc<HydraDoomNode>> {
+        let config = self.context.config();
+        self.store
+            .state()
+            .into_iter()
+            .find(|crd| crd.serves(host, path, &config, &self.context.constants))
+    }
+
+    /// Token in the secret the node's status names, as long as the node owns
+    /// that secret.
+    async fn expected_token(&self, crd: &HydraDoomNode) -> anyhow::Result<Option<Vec<u8>>> {
+        let Some(name) = crd
+            .status
+            .as_ref()
+            .and_then(|status| status.access_token_secret.clone())
+        else {
+            return Ok(None);
+        };
+        let Some(uid) = crd.meta().uid.clone() else {
+            return Ok(None);
+        };
+
+        let api: Api<Secret> =
+            Api::namespaced(self.context.client.clone(), &crd.namespace().unwrap());
+        let Some(secret) = api.get_opt(&name).await? else {
+            return Ok(None);
+        };
+        if !secret
+            .owner_references()
+            .iter()
+            .any(|owner| owner.uid == uid)
+        {
+            warn!(
+                "Secret {} is not owned by node {}, refusing its token.",
+                name,
+                crd.name_any()
+            );
+            return Ok(None);
+        }
+
+        Ok(secret
+            .data
+            .and_then(|data| data.get(TOKEN_KEY).cloned())
+            .map(|token| token.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_original_url() {
+        assert_eq!(
+            split_url("https://node.example.com:443/ws?token=abc"),
+            Some(("node.example.com", "/ws?token=abc"))
+        );
+        assert_eq!(
+            split_url("http://node.example.com"),
+            Some(("node.example.com", "/"))
+        );
+        assert_eq!(split_url("/ws?token=abc"), None);
+    }
+
+    #[test]
+    fn compares_tokens() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secrets"));
+    }
+}
@@ -0,0 +1,45 @@
+use anyhow::{anyhow, bail, Result};
+use kube::{runtime::reflector, Client};
+use std::{env, sync::Arc};
+use tracing::{info, instrument};
+
+use doom_patrol::{
+    activator::{watch_nodes, Activator},
+    config::Config,
+    controller::K8sConstants,
+    reload::{load_config, watch_config},
+};
+
+#[tokio::main]
+#[instrument("activator run", skip_all)]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .init();
+
+    info!("Initiating activator.");
+    let base_config = Config::try_from_env()
+        .map_err(|problems| anyhow!("Invalid config: {}", problems.join("; ")))?;
+    let client = Client::try_default().await?;
+    let config = load_config(client.clone(), base_config.clone()).await?;
+    // The activator only routes requests, so the node settings it never uses
+    // are not required here.
+    let problems = config.validate_routing();
+    if !problems.is_empty() {
+        bail!("Invalid config: {}", problems.join("; "));
+    }
+    let constants = K8sConstants::load(&config)?;
+    let addr = env::var("ACTIVATOR_ADDR").unwrap_or(format!("0.0.0.0:{}", config.activator_port));
+
+    let (store, writer) = reflector::store();
+    let activator = Arc::new(Activator::new(client.clone(), config, constants, store));
+    let config_activator = activator.clone();
+
+    tokio::select! {
+        result = activator.run(&addr) => result,
+        _ = watch_nodes(client.clone(), writer) => Ok(()),
+        _ = watch_config(client, base_config, Config::validate_routing, false, move |config| {
+            config_activator.set_config(config)
+        }) => Ok(()),
+    }
+}
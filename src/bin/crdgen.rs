@@ -1,6 +1,21 @@
-use doom_patrol::custom_resource::HydraDoomNode;
+use doom_patrol::custom_resource::{
+    ClusterHydraDoomConfig, HydraDoomConfig, HydraDoomNode, HydraDoomNodeClaim, HydraDoomNodePool,
+    HydraDoomNodeReport,
+};
 use kube::CustomResourceExt;
 
 fn main() {
-    print!("{}", serde_yaml::to_string(&HydraDoomNode::crd()).unwrap())
+    let crds = [
+        HydraDoomNode::crd(),
+        HydraDoomNodePool::crd(),
+        HydraDoomNodeClaim::crd(),
+        HydraDoomNodeReport::immutable_crd(),
+        HydraDoomConfig::crd(),
+        ClusterHydraDoomConfig::crd(),
+    ];
+    let docs: Vec<String> = crds
+        .iter()
+        .map(|crd| serde_yaml::to_string(crd).unwrap())
+        .collect();
+    print!("{}", docs.join("---\n"))
 }
@@ -1,41 +1,496 @@
-use anyhow::Result;
-use futures::StreamExt;
-use kube::{runtime::controller::Controller, Api, Client};
-use std::sync::Arc;
-use tracing::{error, info, instrument};
+use anyhow::{anyhow, bail, Result};
+use clap::Parser;
+use futures::{channel::mpsc, StreamExt};
+use k8s_openapi::api::core::v1::{ConfigMap, Secret};
+use kube::{
+    runtime::{controller, controller::Controller, reflector, watcher, WatchStreamExt},
+    Api, Client,
+};
+use serde::Deserialize;
+use std::{net::SocketAddr, str::FromStr, sync::Arc, time::Duration};
+use tokio_native_tls::TlsAcceptor;
+use tracing::{error, info, instrument, Level};
 
 use doom_patrol::{
+    auth::AuthServer,
     config::Config,
-    controller::{error_policy, patch_statuses, reconcile, K8sContext},
+    controller::{
+        error_policy, nodes_referencing, patch_statuses, reconcile, watch_pods, K8sConstants,
+        K8sContext, ROLL_LABEL,
+    },
     custom_resource::HydraDoomNode,
+    health::HealthServer,
+    pool::reconcile_pools,
+    reload::{load_config, watch_config},
+    webhook::{load_tls, AdmissionServer},
 };
 
+/// Operator for HydraDoomNode resources.
+///
+/// Every setting below can be given as a flag, an env var or in the config
+/// file, in that order of precedence. Node related settings come from env vars
+/// and the HydraDoomConfig resource.
+// Env vars are looked up by `Cli::with_env` rather than by clap, so that tests
+// can supply their own without touching the process env.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    /// YAML or TOML file with any of the settings below, in camelCase
+    /// [env: OPERATOR_CONFIG_FILE]
+    #[arg(long)]
+    config_file: Option<String>,
+    /// trace, debug, info, warn or error [env: LOG_LEVEL] [default: info]
+    #[arg(long)]
+    log_level: Option<String>,
+    /// text or json [env: LOG_FORMAT] [default: text]
+    #[arg(long)]
+    log_format: Option<String>,
+    /// Namespace to watch [env: WATCH_NAMESPACE]
+    /// [default: the namespace of the kube config]
+    #[arg(long)]
+    namespace: Option<String>,
+    /// Interval of the status loop in milliseconds [env: STATUS_INTERVAL_MS] [default: 5000]
+    #[arg(long)]
+    status_interval_ms: Option<String>,
+    /// Bind address of the metrics endpoint [env: METRICS_ADDR] [default: 0.0.0.0:9090]
+    #[arg(long)]
+    metrics_addr: Option<String>,
+    /// Bind address of the health endpoints [env: HEALTH_ADDR] [default: 0.0.0.0:8082]
+    #[arg(long)]
+    health_addr: Option<String>,
+    /// Maximum number of nodes reconciled at once, 0 for no limit
+    /// [env: RECONCILE_CONCURRENCY] [default: 0]
+    #[arg(long)]
+    concurrency: Option<String>,
+    /// Bind address of the admission webhook [env: WEBHOOK_ADDR] [default: 0.0.0.0:8443]
+    #[arg(long)]
+    webhook_addr: Option<String>,
+    /// PEM certificate of the admission webhook, which only runs when this and
+    /// the key are set [env: WEBHOOK_CERT_FILE]
+    #[arg(long)]
+    webhook_cert_file: Option<String>,
+    /// PEM (PKCS #8) key of the admission webhook [env: WEBHOOK_KEY_FILE]
+    #[arg(long)]
+    webhook_key_file: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+struct FileSettings {
+    log_level: Option<String>,
+    log_format: Option<String>,
+    namespace: Option<String>,
+    status_interval_ms: Option<u64>,
+    metrics_addr: Option<String>,
+    health_addr: Option<String>,
+    concurrency: Option<u16>,
+    webhook_addr: Option<String>,
+    webhook_cert_file: Option<String>,
+    webhook_key_file: Option<String>,
+}
+
+struct Settings {
+    log_level: Level,
+    json_logs: bool,
+    namespace: Option<String>,
+    status_interval: Duration,
+    metrics_addr: SocketAddr,
+    health_addr: SocketAddr,
+    concurrency: u16,
+    webhook_addr: SocketAddr,
+    webhook_tls: Option<TlsAcceptor>,
+}
+
+/// Parses `value`, or `default` when it is not set, recording a problem and
+/// falling back to `default` when it does not parse.
+fn parse_setting<T: FromStr>(
+    problems: &mut Vec<String>,
+    name: &str,
+    value: Option<String>,
+    default: T,
+) -> T {
+    let Some(value) = value else {
+        return default;
+    };
+    value.parse().unwrap_or_else(|_| {
+        problems.push(format!("Invalid {}: {}", name, value));
+        default
+    })
+}
+
+fn read_config_file(path: &str) -> Result<FileSettings> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("Failed to read config file {}: {}", path, err))?;
+    let settings = if path.ends_with(".toml") {
+        toml::from_str(&contents).map_err(|err| err.to_string())
+    } else {
+        serde_yaml::from_str(&contents).map_err(|err| err.to_string())
+    };
+    settings.map_err(|err| anyhow!("Invalid config file {}: {}", path, err))
+}
+
+impl Cli {
+    /// Fills every setting not given as a flag from its env var, looked up
+    /// through `env`.
+    fn with_env(mut self, env: &dyn Fn(&str) -> Option<String>) -> Self {
+        for (setting, var) in [
+            (&mut self.config_file, "OPERATOR_CONFIG_FILE"),
+            (&mut self.log_level, "LOG_LEVEL"),
+            (&mut self.log_format, "LOG_FORMAT"),
+            (&mut self.namespace, "WATCH_NAMESPACE"),
+            (&mut self.status_interval_ms, "STATUS_INTERVAL_MS"),
+            (&mut self.metrics_addr, "METRICS_ADDR"),
+            (&mut self.health_addr, "HEALTH_ADDR"),
+            (&mut self.concurrency, "RECONCILE_CONCURRENCY"),
+            (&mut self.webhook_addr, "WEBHOOK_ADDR"),
+            (&mut self.webhook_cert_file, "WEBHOOK_CERT_FILE"),
+            (&mut self.webhook_key_file, "WEBHOOK_KEY_FILE"),
+        ] {
+            if setting.is_none() {
+                *setting = env(var);
+            }
+        }
+        self
+    }
+
+    /// Resolves flags, env vars, the config file and defaults, recording
+    /// every invalid value in `problems`.
+    fn settings(self, problems: &mut Vec<String>) -> Settings {
+        let file = match &self.config_file {
+            Some(path) => read_config_file(path).unwrap_or_else(|err| {
+                problems.push(err.to_string());
+                FileSettings::default()
+            }),
+            None => FileSettings::default(),
+        };
+
+        let log_level = parse_setting(
+            problems,
+            "log level",
+            self.log_level.or(file.log_level),
+            Level::INFO,
+        );
+        let json_logs = match self.log_format.or(file.log_format).as_deref() {
+            None | Some("text") => false,
+            Some("json") => true,
+            Some(other) => {
+                problems.push(format!("Invalid log format: {}", other));
+                false
+            }
+        };
+        let status_interval_ms = parse_setting(
+            problems,
+            "status interval",
+            self.status_interval_ms
+                .or(file.status_interval_ms.map(|ms| ms.to_string())),
+            5000u64,
+        );
+        if status_interval_ms == 0 {
+            problems.push("Status interval must be positive".to_string());
+        }
+        let metrics_addr = parse_setting(
+            problems,
+            "metrics address",
+            self.metrics_addr.or(file.metrics_addr),
+            SocketAddr::from(([0, 0, 0, 0], 9090)),
+        );
+        let health_addr = parse_setting(
+            problems,
+            "health address",
+            self.health_addr.or(file.health_addr),
+            SocketAddr::from(([0, 0, 0, 0], 8082)),
+        );
+        let concurrency = parse_setting(
+            problems,
+            "reconcile concurrency",
+            self.concurrency.or(file.concurrency.map(|n| n.to_string())),
+            0u16,
+        );
+        let webhook_addr = parse_setting(
+            problems,
+            "webhook address",
+            self.webhook_addr.or(file.webhook_addr),
+            SocketAddr::from(([0, 0, 0, 0], 8443)),
+        );
+        let webhook_tls = match (
+            self.webhook_cert_file.or(file.webhook_cert_file),
+            self.webhook_key_file.or(file.webhook_key_file),
+        ) {
+            (Some(cert_file), Some(key_file)) => load_tls(&cert_file, &key_file)
+                .map_err(|err| problems.push(err.to_string()))
+                .ok(),
+            (None, None) => None,
+            _ => {
+                problems.push("Webhook needs both a certificate and a key".to_string());
+                None
+            }
+        };
+
+        Settings {
+            log_level,
+            json_logs,
+            namespace: self.namespace.or(file.namespace),
+            status_interval: Duration::from_millis(status_interval_ms),
+            metrics_addr,
+            health_addr,
+            concurrency,
+            webhook_addr,
+            webhook_tls,
+        }
+    }
+}
+
 #[tokio::main]
 #[instrument("controller run", skip_all)]
 async fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::INFO)
-        .init();
+    let mut problems = vec![];
+    let settings = Cli::parse()
+        .with_env(&|var| std::env::var(var).ok())
+        .settings(&mut problems);
+
+    let subscriber = tracing_subscriber::fmt().with_max_level(settings.log_level);
+    if settings.json_logs {
+        subscriber.json().init();
+    } else {
+        subscriber.init();
+    }
 
     info!("Initiating operator.");
-    let client = Client::try_default().await?;
-    let config = Config::from_env();
-    let context = Arc::new(K8sContext::new(client.clone(), config));
+    // Collect every problem with the configuration before giving up.
+    let (mut config, env_problems) = Config::read_env();
+    problems.extend(env_problems);
+    config.status_interval = settings.status_interval;
+    let constants = K8sConstants::load(&config)
+        .map_err(|err| problems.push(err.to_string()))
+        .ok();
+    let client = match kube::Config::infer().await {
+        Ok(mut kube_config) => {
+            if let Some(namespace) = &settings.namespace {
+                kube_config.default_namespace = namespace.clone();
+            }
+            Client::try_from(kube_config)
+                .map_err(|err| problems.push(format!("Failed to create client: {}", err)))
+                .ok()
+        }
+        Err(err) => {
+            problems.push(format!("Failed to load kube config: {}", err));
+            None
+        }
+    };
+    if let Some(client) = &client {
+        match load_config(client.clone(), config.clone()).await {
+            Ok(effective) => problems.extend(effective.validate()),
+            Err(err) => problems.push(format!("Failed to load HydraDoomConfig: {}", err)),
+        }
+    }
+    let (Some(constants), Some(client), true) = (constants, client, problems.is_empty()) else {
+        for problem in &problems {
+            error!("{}", problem);
+        }
+        bail!("Found {} configuration problem(s)", problems.len());
+    };
 
-    // Create controller for MyApp custom resource
-    let api: Api<HydraDoomNode> = Api::default_namespaced(client);
+    let (pods, pods_writer) = reflector::store();
+    let (nodes, nodes_writer) = reflector::store();
+    let (secrets, secrets_writer) = reflector::store();
+    let (configmaps, configmaps_writer) = reflector::store();
+    let context = Arc::new(K8sContext::new(
+        client.clone(),
+        config,
+        constants,
+        pods,
+        nodes,
+        secrets,
+        configmaps,
+    ));
+    let pods_watcher = watch_pods(client.clone(), pods_writer, nodes_writer);
+    let (reconcile_all, reconcile_all_rx) = mpsc::unbounded();
+
+    // Controller for HydraDoomNode resources in the watched namespace.
+    let api: Api<HydraDoomNode> = Api::default_namespaced(client.clone());
     info!("Running controller.");
     let controller = Controller::new(api, Default::default())
+        .with_config(controller::Config::default().concurrency(settings.concurrency));
+    let health = Arc::new(HealthServer::new(controller.store()));
+    let (store, secrets_context) = (controller.store(), context.clone());
+    let secrets_mapper =
+        move |secret: Secret| nodes_referencing(&store, &secrets_context.config(), &secret);
+    let (store, configmaps_context) = (controller.store(), context.clone());
+    let configmaps_mapper = move |configmap: ConfigMap| {
+        nodes_referencing(&store, &configmaps_context.config(), &configmap)
+    };
+    let reconcile_health = health.clone();
+    let auth_store = controller.store();
+    // The reflectors update the stores the config hash is read from before
+    // passing the change on, so the reconcile it triggers sees it.
+    let roll_watcher = watcher::Config::default().labels(&format!("{}=true", ROLL_LABEL));
+    let secret_events = reflector::reflector(
+        secrets_writer,
+        watcher(
+            Api::<Secret>::default_namespaced(client.clone()),
+            roll_watcher.clone(),
+        ),
+    )
+    .default_backoff()
+    .touched_objects();
+    let configmap_events = reflector::reflector(
+        configmaps_writer,
+        watcher(Api::<ConfigMap>::default_namespaced(client), roll_watcher),
+    )
+    .default_backoff()
+    .touched_objects();
+    let controller = controller
+        .watches_stream(secret_events, secrets_mapper)
+        .watches_stream(configmap_events, configmaps_mapper)
+        .reconcile_all_on(reconcile_all_rx)
         .run(reconcile, error_policy, context.clone())
-        .for_each(|res| async move {
-            match res {
-                Ok(o) => info!("Reconciled {:?}", o),
-                Err(e) => error!("Reconcile failed: {:?}", e),
+        .for_each(move |res| {
+            reconcile_health.record_reconcile(res.is_ok());
+            async move {
+                match res {
+                    Ok(o) => info!("Reconciled {:?}", o),
+                    Err(e) => error!("Reconcile failed: {:?}", e),
+                }
             }
         });
+    let config_context = context.clone();
+    let config_watcher = watch_config(
+        context.client.clone(),
+        context.base_config.clone(),
+        Config::validate,
+        true,
+        move |config| {
+            config_context.set_config(config);
+            let _ = reconcile_all.unbounded_send(());
+        },
+    );
     let patch_statuses_controller = patch_statuses(context.clone());
+    let pools_controller = reconcile_pools(context.clone());
+    let auth_server = async {
+        match &context.config().auth_url {
+            Some(_) => {
+                Arc::new(AuthServer::new(context.clone(), auth_store))
+                    .run(&context.config().auth_addr)
+                    .await
+            }
+            None => Ok(()),
+        }
+    };
+    // Every endpoint is served on both addresses, so one listener is enough
+    // when they are the same.
+    let health_server = health.clone().run(settings.health_addr);
+    let metrics_server = async {
+        if settings.metrics_addr == settings.health_addr {
+            return Ok(());
+        }
+        health.clone().run(settings.metrics_addr).await
+    };
+    let webhook_server = async {
+        match settings.webhook_tls {
+            Some(acceptor) => {
+                Arc::new(AdmissionServer::new(context.clone(), acceptor))
+                    .run(settings.webhook_addr)
+                    .await
+            }
+            None => Ok(()),
+        }
+    };
 
-    let _ = tokio::join!(controller, patch_statuses_controller);
+    let _ = tokio::join!(
+        controller,
+        config_watcher,
+        patch_statuses_controller,
+        pods_watcher,
+        pools_controller,
+        auth_server,
+        health.watch_readiness(),
+        health_server,
+        metrics_server,
+        webhook_server
+    );
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_file(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn flags_win_over_env_vars_over_the_config_file() {
+        let path = config_file(
+            "operator.yaml",
+            "logLevel: warn\n\
+             statusIntervalMs: 1000\n\
+             healthAddr: 127.0.0.1:1\n\
+             metricsAddr: 127.0.0.1:2\n",
+        );
+        let env = |var: &str| match var {
+            "HEALTH_ADDR" => Some("127.0.0.1:3".to_string()),
+            "METRICS_ADDR" => Some("127.0.0.1:4".to_string()),
+            _ => None,
+        };
+
+        let mut problems = vec![];
+        let settings = Cli::parse_from([
+            "operator",
+            "--config-file",
+            &path,
+            "--metrics-addr",
+            "127.0.0.1:5",
+        ])
+        .with_env(&env)
+        .settings(&mut problems);
+
+        assert_eq!(problems, Vec::<String>::new());
+        assert_eq!(settings.metrics_addr, "127.0.0.1:5".parse().unwrap());
+        assert_eq!(settings.health_addr, "127.0.0.1:3".parse().unwrap());
+        assert_eq!(settings.log_level, Level::WARN);
+        assert_eq!(settings.status_interval, Duration::from_secs(1));
+        assert_eq!(settings.concurrency, 0);
+        assert!(!settings.json_logs);
+    }
+
+    #[test]
+    fn collects_every_problem() {
+        let path = config_file("invalid.toml", "reconcileConcurrency = 4\n");
+
+        let mut problems = vec![];
+        Cli::parse_from([
+            "operator",
+            "--config-file",
+            &path,
+            "--log-level",
+            "loud",
+            "--log-format",
+            "xml",
+            "--status-interval-ms",
+            "0",
+            "--webhook-addr",
+            "nowhere",
+            "--webhook-cert-file",
+            "/tls/tls.crt",
+        ])
+        .with_env(&|_| None)
+        .settings(&mut problems);
+
+        assert_eq!(problems.len(), 6, "{:?}", problems);
+        assert!(problems[0].starts_with("Invalid config file"));
+        assert_eq!(
+            problems[1..],
+            [
+                "Invalid log level: loud",
+                "Invalid log format: xml",
+                "Status interval must be positive",
+                "Invalid webhook address: nowhere",
+                "Webhook needs both a certificate and a key",
+            ]
+        );
+    }
+}
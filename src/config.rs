@@ -1,12 +1,77 @@
-use lazy_static::lazy_static;
-use std::env;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    env,
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
 
-lazy_static! {
-    static ref CONTROLLER_CONFIG: Config = Config::from_env();
+use crate::{controller::K8sConstants, custom_resource::HydraDoomConfigSpec};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TlsMode {
+    /// Plain ws://, no TLS anywhere.
+    None,
+    /// Every ingress uses the shared (wildcard) certificate in `tls_secret`.
+    Wildcard,
+    /// Each ingress requests its own certificate through cert-manager.
+    CertManager,
+    /// TLS is terminated before the ingress (e.g. at a load balancer).
+    Upstream,
+}
+impl TlsMode {
+    pub fn from_env(env: &dyn Fn(&str) -> Option<String>) -> Result<Self, String> {
+        match env("TLS_MODE").as_deref() {
+            None | Some("none") => Ok(Self::None),
+            Some("wildcard") => Ok(Self::Wildcard),
+            Some("cert-manager") => Ok(Self::CertManager),
+            Some("upstream") => Ok(Self::Upstream),
+            Some(other) => Err(format!("Invalid TLS_MODE env var: {}", other)),
+        }
+    }
 }
 
-pub fn get_config() -> &'static Config {
-    &CONTROLLER_CONFIG
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExposureMode {
+    Ingress,
+    Gateway,
+}
+impl ExposureMode {
+    pub fn from_env(env: &dyn Fn(&str) -> Option<String>) -> Result<Self, String> {
+        match env("EXPOSURE_MODE").as_deref() {
+            None | Some("ingress") => Ok(Self::Ingress),
+            Some("gateway") => Ok(Self::Gateway),
+            Some(other) => Err(format!("Invalid EXPOSURE_MODE env var: {}", other)),
+        }
+    }
+}
+
+/// Reference to a key of a Secret in the nodes' namespace, given in env vars as
+/// `<secret name>:<key>`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct SecretKeyRef {
+    pub name: String,
+    pub key: String,
+}
+impl SecretKeyRef {
+    pub fn from_env(
+        env: &dyn Fn(&str) -> Option<String>,
+        var: &str,
+    ) -> Result<Option<Self>, String> {
+        let Some(value) = env(var) else {
+            return Ok(None);
+        };
+        let (name, key) = value
+            .split_once(':')
+            .ok_or(format!("Invalid {} env var, expected <secret>:<key>.", var))?;
+        Ok(Some(Self {
+            name: name.to_string(),
+            key: key.to_string(),
+        }))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -16,33 +81,639 @@ pub struct Config {
     pub sidecar_image: String,
     pub configmap: String,
     pub secret: String,
-    pub blockfrost_key: String,
+    /// Raw value, only used to fill the operator-managed credentials secret.
+    pub blockfrost_key: Option<String>,
+    pub blockfrost_key_ref: SecretKeyRef,
     pub external_domain: String,
     pub external_port: String,
     pub admin_addr: String,
     pub hydra_scripts_tx_id: String,
     pub dmtr_project_id: String,
-    pub dmtr_api_key: String,
+    /// Raw value, only used to fill the operator-managed credentials secret.
+    pub dmtr_api_key: Option<String>,
+    pub dmtr_api_key_ref: SecretKeyRef,
+    pub credentials_secret: String,
     pub dmtr_port_name: String,
+    pub activator_service: Option<String>,
+    pub activator_port: i32,
+    /// How often the status loop runs, set by the operator's command line.
+    pub status_interval: Duration,
+    pub status_timeout: Duration,
+    pub status_concurrency: usize,
+    pub status_breaker_threshold: u32,
+    pub status_breaker_cooldown: Duration,
+    pub report_dir: Option<String>,
+    pub tls_mode: TlsMode,
+    pub tls_secret: Option<String>,
+    pub tls_external_port: String,
+    pub cert_manager_issuer: Option<String>,
+    pub cert_manager_issuer_kind: String,
+    pub exposure_mode: ExposureMode,
+    /// Overrides `K8sConstants::ingress_class_name`; see `ingress_class`.
+    pub ingress_class_name: Option<String>,
+    /// Replaces `K8sConstants::ingress_annotations`; see `ingress_annotations`.
+    pub ingress_annotations: Option<BTreeMap<String, String>>,
+    pub gateway_name: Option<String>,
+    pub gateway_namespace: Option<String>,
+    pub gateway_section_name: Option<String>,
+    pub route_timeout: String,
+    pub peer_node_address: Option<String>,
+    pub auth_url: Option<String>,
+    pub auth_addr: String,
+    pub network_policies: bool,
+    pub ingress_namespace: String,
+    /// Namespace the operator runs in, where its `HydraDoomConfig` lives.
+    /// Given through the downward API as `POD_NAMESPACE`; nodes may be
+    /// watched in another one (`WATCH_NAMESPACE`).
+    pub operator_namespace: String,
+    pub prometheus_namespace: Option<String>,
+    pub egress_cidrs: Vec<String>,
+    pub egress_ports: Vec<i32>,
+    pub external_host_template: String,
+    pub external_path_template: Option<String>,
+    pub external_dns: bool,
+    pub external_dns_ttl: Option<u32>,
+    pub external_dns_target: Option<String>,
+    pub dns_nameserver: Option<SocketAddr>,
+    /// Whether nodes with an initializing or open head are restarted when their
+    /// referenced secrets or configmap change. Off by default, as a restart
+    /// drops the head.
+    pub roll_open_heads: bool,
+    /// Name of the `HydraDoomConfig` in the operator's namespace whose
+    /// settings override the ones above.
+    pub config_resource: String,
+    /// YAML or TOML file overriding the `K8sConstants` defaults.
+    pub settings_file: Option<String>,
 }
 
+pub static BLOCKFROST_KEY_KEY: &str = "blockfrost-key";
+pub static DMTR_API_KEY_KEY: &str = "dmtr-api-key";
+
 impl Config {
-    pub fn from_env() -> Self {
-        Self {
-            image: env::var("IMAGE").unwrap_or("ghcr.io/cardano-scaling/hydra-node".into()),
-            open_head_image: env::var("OPEN_HEAD_IMAGE").expect("Missing OPEN_HEAD_IMAGE env var"),
-            sidecar_image: env::var("SIDECAR_IMAGE").expect("Missing SIDECAR_IMAGE env var"),
-            configmap: env::var("CONFIGMAP").expect("Missing CONFIGMAP env var"),
-            secret: env::var("SECRET").expect("Missing SECRET env var"),
-            blockfrost_key: env::var("BLOCKFROST_KEY").expect("Missing BLOCKFROST_KEY env var"),
-            external_domain: env::var("EXTERNAL_DOMAIN").expect("Missing EXTERNAL_DOMAIN env var."),
-            external_port: env::var("EXTERNAL_PORT").expect("Missing EXTERNAL_PORT env var."),
-            admin_addr: env::var("ADMIN_ADDR").expect("Missing ADMIN_ADDR env var."),
-            hydra_scripts_tx_id: env::var("HYDRA_SCRIPTS_TX_ID")
-                .expect("Missing HYDRA_SCRIPTS_TX_ID env var."),
-            dmtr_project_id: env::var("DMTR_PROJECT_ID").expect("Missing DMTR_PROJECT_ID env var."),
-            dmtr_api_key: env::var("DMTR_API_KEY").expect("Missing DMTR_API_KEY env var."),
-            dmtr_port_name: env::var("DMTR_PORT_NAME").expect("Missing DMTR_PORT_NAME env var."),
+    /// Raw credentials the operator has to store in its own secret, keyed by
+    /// secret key. Credentials given as references are left alone.
+    pub fn managed_credentials(&self) -> BTreeMap<String, String> {
+        [
+            (&self.blockfrost_key, &self.blockfrost_key_ref),
+            (&self.dmtr_api_key, &self.dmtr_api_key_ref),
+        ]
+        .into_iter()
+        .filter(|(_, reference)| reference.name == self.credentials_secret)
+        .filter_map(|(value, reference)| value.clone().map(|value| (reference.key.clone(), value)))
+        .collect()
+    }
+
+    /// Ingress class of the node ingresses. `INGRESS_CLASS_NAME` wins over
+    /// the settings file, which wins over the built-in default.
+    pub fn ingress_class(&self, constants: &K8sConstants) -> String {
+        self.ingress_class_name
+            .clone()
+            .unwrap_or(constants.ingress_class_name.clone())
+    }
+
+    /// Annotations every node ingress starts from. `INGRESS_ANNOTATIONS`
+    /// replaces the ones from the settings file (or the built-in defaults)
+    /// as a whole; the two are not merged.
+    pub fn ingress_annotations(&self, constants: &K8sConstants) -> BTreeMap<String, String> {
+        self.ingress_annotations
+            .clone()
+            .unwrap_or(constants.ingress_annotations.clone())
+    }
+
+    /// Settings that can come from either env vars or the `HydraDoomConfig`
+    /// and are still missing or malformed. Empty when the config is usable.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = self.validate_routing();
+        let mut check = |name: &str, value: &str, valid: fn(&str) -> bool, expected: &str| {
+            check_setting(&mut problems, name, value, valid, expected)
+        };
+        check("image", &self.image, is_image, "image reference");
+        check(
+            "openHeadImage",
+            &self.open_head_image,
+            is_image,
+            "image reference",
+        );
+        check(
+            "sidecarImage",
+            &self.sidecar_image,
+            is_image,
+            "image reference",
+        );
+        check("configmap", &self.configmap, is_dns_name, "resource name");
+        check("secret", &self.secret, is_dns_name, "resource name");
+        check("adminAddr", &self.admin_addr, is_address, "Cardano address");
+        check(
+            "hydraScriptsTxId",
+            &self.hydra_scripts_tx_id,
+            is_tx_ids,
+            "list of transaction ids",
+        );
+        check("dmtrProjectId", &self.dmtr_project_id, |_| true, "");
+        check("dmtrPortName", &self.dmtr_port_name, |_| true, "");
+        for (name, reference) in [
+            ("blockfrostKeyRef", &self.blockfrost_key_ref),
+            ("dmtrApiKeyRef", &self.dmtr_api_key_ref),
+        ] {
+            check(
+                &format!("{}.name", name),
+                &reference.name,
+                is_dns_name,
+                "resource name",
+            );
+            check(
+                &format!("{}.key", name),
+                &reference.key,
+                is_secret_key,
+                "secret key",
+            );
+        }
+        problems
+    }
+
+    /// The part of `validate` the activator depends on: what it needs to
+    /// work out which node a request is for.
+    pub fn validate_routing(&self) -> Vec<String> {
+        let mut problems = vec![];
+        let mut check = |name: &str, value: &str, valid: fn(&str) -> bool, expected: &str| {
+            check_setting(&mut problems, name, value, valid, expected)
+        };
+        check(
+            "externalDomain",
+            &self.external_domain,
+            is_dns_name,
+            "domain name",
+        );
+        check("externalPort", &self.external_port, is_port, "port");
+        check(
+            "EXTERNAL_HOST_TEMPLATE",
+            &self.external_host_template,
+            is_host_template,
+            "host template",
+        );
+        if let Some(template) = &self.external_path_template {
+            check(
+                "EXTERNAL_PATH_TEMPLATE",
+                template,
+                |template| template.starts_with('/'),
+                "path template",
+            );
         }
+        problems
+    }
+
+    /// This config with every setting given in the `HydraDoomConfig` spec
+    /// taking precedence.
+    pub fn with_overrides(&self, spec: &HydraDoomConfigSpec) -> Config {
+        let mut config = self.clone();
+        let overrides = [
+            (&mut config.image, &spec.image),
+            (&mut config.open_head_image, &spec.open_head_image),
+            (&mut config.sidecar_image, &spec.sidecar_image),
+            (&mut config.configmap, &spec.configmap),
+            (&mut config.secret, &spec.secret),
+            (&mut config.external_domain, &spec.external_domain),
+            (&mut config.external_port, &spec.external_port),
+            (&mut config.admin_addr, &spec.admin_addr),
+            (&mut config.hydra_scripts_tx_id, &spec.hydra_scripts_tx_id),
+            (&mut config.dmtr_project_id, &spec.dmtr_project_id),
+            (&mut config.dmtr_port_name, &spec.dmtr_port_name),
+        ];
+        for (setting, value) in overrides {
+            if let Some(value) = value {
+                *setting = value.clone();
+            }
+        }
+        if let Some(reference) = &spec.blockfrost_key_ref {
+            config.blockfrost_key_ref = reference.clone();
+        }
+        if let Some(reference) = &spec.dmtr_api_key_ref {
+            config.dmtr_api_key_ref = reference.clone();
+        }
+        config
+    }
+
+    /// Config from env vars, failing with every malformed value found.
+    /// Settings that may still come from the `HydraDoomConfig` are not
+    /// required here; see `validate`.
+    pub fn try_from_env() -> Result<Self, Vec<String>> {
+        let (config, problems) = Self::read_env();
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Config from env vars along with all problems found reading them.
+    /// Malformed values are replaced by their defaults.
+    pub fn read_env() -> (Self, Vec<String>) {
+        Self::read_env_from(&|var| env::var(var).ok())
+    }
+
+    /// Like `read_env`, looking every variable up through `env` instead of
+    /// the process env.
+    pub fn read_env_from(env: &dyn Fn(&str) -> Option<String>) -> (Self, Vec<String>) {
+        let mut problems = vec![];
+
+        let tls_mode = check(&mut problems, TlsMode::from_env(env)).unwrap_or(TlsMode::None);
+        let exposure_mode =
+            check(&mut problems, ExposureMode::from_env(env)).unwrap_or(ExposureMode::Ingress);
+        let blockfrost_key_secret = check(
+            &mut problems,
+            SecretKeyRef::from_env(env, "BLOCKFROST_KEY_SECRET"),
+        )
+        .flatten();
+        let dmtr_api_key_secret = check(
+            &mut problems,
+            SecretKeyRef::from_env(env, "DMTR_API_KEY_SECRET"),
+        )
+        .flatten();
+        let activator_port = check(&mut problems, parse_env(env, "ACTIVATOR_PORT")).flatten();
+        let status_timeout_ms = check(&mut problems, parse_env(env, "STATUS_TIMEOUT_MS")).flatten();
+        let status_concurrency =
+            check(&mut problems, parse_env(env, "STATUS_CONCURRENCY")).flatten();
+        let status_breaker_threshold =
+            check(&mut problems, parse_env(env, "STATUS_BREAKER_THRESHOLD")).flatten();
+        let status_breaker_cooldown_secs = check(
+            &mut problems,
+            parse_env(env, "STATUS_BREAKER_COOLDOWN_SECS"),
+        )
+        .flatten();
+        let external_dns_ttl = check(&mut problems, parse_env(env, "EXTERNAL_DNS_TTL")).flatten();
+        let ingress_annotations = check(
+            &mut problems,
+            env("INGRESS_ANNOTATIONS")
+                .map(|annotations| {
+                    serde_json::from_str(&annotations).map_err(|_| {
+                        "Invalid INGRESS_ANNOTATIONS env var, expected a JSON object.".to_string()
+                    })
+                })
+                .transpose(),
+        )
+        .flatten();
+        let dns_nameserver = check(
+            &mut problems,
+            env("DNS_NAMESERVER")
+                .map(|nameserver| {
+                    nameserver
+                        .parse()
+                        .or_else(|_| {
+                            nameserver
+                                .parse::<IpAddr>()
+                                .map(|ip| SocketAddr::new(ip, 53))
+                        })
+                        .map_err(|_| "Invalid DNS_NAMESERVER env var.".to_string())
+                })
+                .transpose(),
+        )
+        .flatten();
+        let egress_ports = check(
+            &mut problems,
+            env("EGRESS_PORTS")
+                .unwrap_or("443".into())
+                .split(',')
+                .map(|port| {
+                    port.trim()
+                        .parse()
+                        .map_err(|_| "Invalid EGRESS_PORTS env var.".to_string())
+                })
+                .collect(),
+        )
+        .unwrap_or_default();
+        let tls_secret = env("TLS_SECRET");
+        if tls_mode == TlsMode::Wildcard && tls_secret.is_none() {
+            problems.push("Missing TLS_SECRET env var.".to_string());
+        }
+        let cert_manager_issuer = env("CERT_MANAGER_ISSUER");
+        if tls_mode == TlsMode::CertManager && cert_manager_issuer.is_none() {
+            problems.push("Missing CERT_MANAGER_ISSUER env var.".to_string());
+        }
+        let gateway_name = env("GATEWAY_NAME");
+        if exposure_mode == ExposureMode::Gateway && gateway_name.is_none() {
+            problems.push("Missing GATEWAY_NAME env var.".to_string());
+        }
+        let network_policies = env("NETWORK_POLICIES").is_some_and(|value| value == "true");
+        // Egress has to be opted into explicitly; an empty list allows none.
+        let egress_cidrs = env("EGRESS_CIDRS");
+        if network_policies && egress_cidrs.is_none() {
+            problems.push("Missing EGRESS_CIDRS env var.".to_string());
+        }
+        let auth_url = env("AUTH_URL");
+        // HTTPRoutes have no portable external auth filter, so tokens would
+        // be issued without anything checking them.
+        if exposure_mode == ExposureMode::Gateway && auth_url.is_some() {
+            problems.push("AUTH_URL is not supported with EXPOSURE_MODE=gateway.".to_string());
+        }
+        let credentials_secret =
+            env("CREDENTIALS_SECRET").unwrap_or("hydra-doom-credentials".into());
+        let blockfrost_key = env("BLOCKFROST_KEY");
+        let dmtr_api_key = env("DMTR_API_KEY");
+        let config = Self {
+            image: env("IMAGE").unwrap_or("ghcr.io/cardano-scaling/hydra-node".into()),
+            open_head_image: env("OPEN_HEAD_IMAGE").unwrap_or_default(),
+            sidecar_image: env("SIDECAR_IMAGE").unwrap_or_default(),
+            configmap: env("CONFIGMAP").unwrap_or_default(),
+            secret: env("SECRET").unwrap_or_default(),
+            blockfrost_key_ref: blockfrost_key_secret
+                .or(blockfrost_key.as_ref().map(|_| SecretKeyRef {
+                    name: credentials_secret.clone(),
+                    key: BLOCKFROST_KEY_KEY.to_string(),
+                }))
+                .unwrap_or_default(),
+            blockfrost_key,
+            external_domain: env("EXTERNAL_DOMAIN").unwrap_or_default(),
+            external_port: env("EXTERNAL_PORT").unwrap_or_default(),
+            admin_addr: env("ADMIN_ADDR").unwrap_or_default(),
+            hydra_scripts_tx_id: env("HYDRA_SCRIPTS_TX_ID").unwrap_or_default(),
+            dmtr_project_id: env("DMTR_PROJECT_ID").unwrap_or_default(),
+            dmtr_api_key_ref: dmtr_api_key_secret
+                .or(dmtr_api_key.as_ref().map(|_| SecretKeyRef {
+                    name: credentials_secret.clone(),
+                    key: DMTR_API_KEY_KEY.to_string(),
+                }))
+                .unwrap_or_default(),
+            dmtr_api_key,
+            credentials_secret,
+            dmtr_port_name: env("DMTR_PORT_NAME").unwrap_or_default(),
+            activator_service: env("ACTIVATOR_SERVICE"),
+            activator_port: activator_port.unwrap_or(8080),
+            status_interval: Duration::from_secs(5),
+            status_timeout: Duration::from_millis(status_timeout_ms.unwrap_or(2000)),
+            status_concurrency: status_concurrency.unwrap_or(16),
+            status_breaker_threshold: status_breaker_threshold.unwrap_or(3),
+            status_breaker_cooldown: Duration::from_secs(
+                status_breaker_cooldown_secs.unwrap_or(30),
+            ),
+            report_dir: env("REPORT_DIR"),
+            tls_secret,
+            tls_mode,
+            gateway_name,
+            exposure_mode,
+            ingress_class_name: env("INGRESS_CLASS_NAME"),
+            ingress_annotations,
+            gateway_namespace: env("GATEWAY_NAMESPACE"),
+            gateway_section_name: env("GATEWAY_SECTION_NAME"),
+            route_timeout: env("ROUTE_TIMEOUT").unwrap_or("3600s".into()),
+            peer_node_address: env("PEER_NODE_ADDRESS"),
+            auth_url,
+            auth_addr: env("AUTH_ADDR").unwrap_or("0.0.0.0:8081".into()),
+            network_policies,
+            ingress_namespace: env("INGRESS_NAMESPACE").unwrap_or("ingress-nginx".into()),
+            operator_namespace: env("OPERATOR_NAMESPACE")
+                .or(env("POD_NAMESPACE"))
+                .unwrap_or("hydra-doom".into()),
+            prometheus_namespace: env("PROMETHEUS_NAMESPACE"),
+            egress_cidrs: egress_cidrs
+                .unwrap_or_default()
+                .split(',')
+                .map(|cidr| cidr.trim().to_string())
+                .filter(|cidr| !cidr.is_empty())
+                .collect(),
+            external_host_template: env("EXTERNAL_HOST_TEMPLATE")
+                .unwrap_or("{name}.{domain}".into()),
+            external_path_template: env("EXTERNAL_PATH_TEMPLATE")
+                .map(|path| path.trim_end_matches('/').to_string()),
+            external_dns: env("EXTERNAL_DNS").is_some_and(|value| value == "true"),
+            external_dns_ttl,
+            external_dns_target: env("EXTERNAL_DNS_TARGET"),
+            dns_nameserver,
+            egress_ports,
+            tls_external_port: env("TLS_EXTERNAL_PORT").unwrap_or("443".into()),
+            cert_manager_issuer,
+            cert_manager_issuer_kind: env("CERT_MANAGER_ISSUER_KIND")
+                .unwrap_or("ClusterIssuer".into()),
+            roll_open_heads: env("ROLL_OPEN_HEADS").is_some_and(|value| value == "true"),
+            config_resource: env("HYDRA_DOOM_CONFIG").unwrap_or("hydra-doom".into()),
+            settings_file: env("SETTINGS_FILE"),
+        };
+        (config, problems)
+    }
+}
+
+/// Parsed value of an optional env var.
+fn parse_env<T: FromStr>(
+    env: &dyn Fn(&str) -> Option<String>,
+    var: &str,
+) -> Result<Option<T>, String> {
+    env(var)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("Invalid {} env var.", var))
+        })
+        .transpose()
+}
+
+/// `[registry[:port]/]repository[:tag][@digest]`, as accepted by `docker pull`.
+fn is_image(value: &str) -> bool {
+    let component = r"[a-z0-9]+(?:(?:[._]|__|-+)[a-z0-9]+)*";
+    let pattern = format!(
+        r"^(?:[a-zA-Z0-9.-]+(?::[0-9]+)?/)?{component}(?:/{component})*(?::[\w][\w.-]{{0,127}})?(?:@sha256:[0-9a-f]{{64}})?$"
+    );
+    Regex::new(&pattern).unwrap().is_match(value)
+}
+
+/// RFC 1123 subdomain, which is also what resource names have to be.
+fn is_dns_name(value: &str) -> bool {
+    value.len() <= 253
+        && Regex::new(r"^[a-z0-9](?:[-a-z0-9]*[a-z0-9])?(?:\.[a-z0-9](?:[-a-z0-9]*[a-z0-9])?)*$")
+            .unwrap()
+            .is_match(value)
+}
+
+fn is_secret_key(value: &str) -> bool {
+    value.len() <= 253 && Regex::new(r"^[-._a-zA-Z0-9]+$").unwrap().is_match(value)
+}
+
+fn is_port(value: &str) -> bool {
+    value.parse::<u16>().is_ok_and(|port| port != 0)
+}
+
+fn is_address(value: &str) -> bool {
+    bech32::decode(value).is_ok_and(|(hrp, _)| matches!(hrp.as_str(), "addr" | "addr_test"))
+}
+
+/// Host template that renders to a domain name for any node.
+fn is_host_template(value: &str) -> bool {
+    is_dns_name(
+        &value
+            .replace("{name}", "node")
+            .replace("{namespace}", "namespace")
+            .replace("{domain}", "example.com"),
+    )
+}
+
+/// One or more comma separated transaction ids.
+fn is_tx_ids(value: &str) -> bool {
+    value
+        .split(',')
+        .all(|id| id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Records a problem if the setting `name` is empty or not `valid`.
+fn check_setting(
+    problems: &mut Vec<String>,
+    name: &str,
+    value: &str,
+    valid: fn(&str) -> bool,
+    expected: &str,
+) {
+    if value.is_empty() {
+        problems.push(format!("{} is not set", name));
+    } else if !valid(value) {
+        problems.push(format!("{} is not a valid {}: {}", name, expected, value));
+    }
+}
+
+/// Value of `result`, recording the problem if there is one.
+fn check<T>(problems: &mut Vec<String>, result: Result<T, String>) -> Option<T> {
+    result.map_err(|problem| problems.push(problem)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> Config {
+        let (mut config, _) = Config::read_env_from(&|_| None);
+        config.image = "ghcr.io/cardano-scaling/hydra-node:0.19.0".to_string();
+        config.open_head_image = "ghcr.io/demeter-run/doom-patrol-hydra:latest".to_string();
+        config.sidecar_image = "ghcr.io/demeter-run/doom-patrol-metrics-exporter@sha256:\
+            0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+            .to_string();
+        config.configmap = "hydra-pod-config".to_string();
+        config.secret = "hydra-pod-admin-key".to_string();
+        config.external_domain = "us-east-1.hydra-doom.sundae.fi".to_string();
+        config.external_port = "443".to_string();
+        config.admin_addr =
+            "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz".to_string();
+        config.hydra_scripts_tx_id = [
+            "03f8deb122fbbd98af8eb58ef56feda37728ec957d39586b78198a0cf624412a",
+            "ab1d9f8cca896bca06b70df74860deecf20774e03d8562aecaed37525f6ebead",
+        ]
+        .join(",");
+        config.dmtr_project_id = "project".to_string();
+        config.dmtr_port_name = "port".to_string();
+        config.blockfrost_key_ref = SecretKeyRef {
+            name: "hydra-pod-credentials".to_string(),
+            key: BLOCKFROST_KEY_KEY.to_string(),
+        };
+        config.dmtr_api_key_ref = SecretKeyRef {
+            name: "hydra-pod-credentials".to_string(),
+            key: DMTR_API_KEY_KEY.to_string(),
+        };
+        config
+    }
+
+    #[test]
+    fn accepts_valid_config() {
+        assert_eq!(valid_config().validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn reports_missing_settings() {
+        let mut config = valid_config();
+        config.image = String::new();
+        config.dmtr_api_key_ref.key = String::new();
+        assert_eq!(
+            config.validate(),
+            vec!["image is not set", "dmtrApiKeyRef.key is not set"]
+        );
+    }
+
+    #[test]
+    fn reports_malformed_settings() {
+        let mut config = valid_config();
+        config.image = "ghcr.io/Hydra Node".to_string();
+        config.secret = "Admin_Key".to_string();
+        config.external_domain = "hydra-doom..sundae.fi".to_string();
+        config.external_port = "https".to_string();
+        config.admin_addr =
+            "stake1u9ylzsgxaa6xctf4juup682ar3juj85n8tx3hthnljg47zctvm3rc".to_string();
+        config.hydra_scripts_tx_id = "03f8deb1".to_string();
+
+        let problems = config.validate();
+        for setting in [
+            "image",
+            "secret",
+            "externalDomain",
+            "externalPort",
+            "adminAddr",
+            "hydraScriptsTxId",
+        ] {
+            assert!(
+                problems
+                    .iter()
+                    .any(|problem| problem.starts_with(&format!("{} is not a valid", setting))),
+                "{:?}",
+                problems
+            );
+        }
+        assert_eq!(problems.len(), 6);
+    }
+
+    #[test]
+    fn activator_env_is_enough_to_route() {
+        // What bootstrap/stage2/activator.tf sets, with no HydraDoomConfig.
+        let env = BTreeMap::from([
+            ("EXTERNAL_DOMAIN", "us-east-1.hydra-doom.sundae.fi"),
+            ("EXTERNAL_PORT", "443"),
+            ("ACTIVATOR_PORT", "8080"),
+            ("EXTERNAL_HOST_TEMPLATE", "{namespace}.{domain}"),
+            ("EXTERNAL_PATH_TEMPLATE", "/{name}"),
+        ]);
+        let (config, problems) =
+            Config::read_env_from(&|var| env.get(var).map(|value| value.to_string()));
+
+        assert_eq!(problems, Vec::<String>::new());
+        assert_eq!(config.validate_routing(), Vec::<String>::new());
+        assert!(!config.validate().is_empty());
+    }
+
+    #[test]
+    fn reports_malformed_routing_templates() {
+        let mut config = valid_config();
+        config.external_host_template = "{name}_{domain}".to_string();
+        config.external_path_template = Some("{name}".to_string());
+        assert_eq!(
+            config.validate_routing(),
+            vec![
+                "EXTERNAL_HOST_TEMPLATE is not a valid host template: {name}_{domain}",
+                "EXTERNAL_PATH_TEMPLATE is not a valid path template: {name}",
+            ]
+        );
+    }
+
+    #[test]
+    fn ingress_env_takes_precedence_over_constants() {
+        let constants = K8sConstants {
+            ingress_class_name: "from-settings".to_string(),
+            ingress_annotations: BTreeMap::from([
+                ("settings".to_string(), "1".to_string()),
+                ("shared".to_string(), "settings".to_string()),
+            ]),
+            ..Default::default()
+        };
+
+        let (config, _) = Config::read_env_from(&|_| None);
+        assert_eq!(config.ingress_class(&constants), "from-settings");
+        assert_eq!(
+            config.ingress_annotations(&constants),
+            constants.ingress_annotations
+        );
+
+        let (config, problems) = Config::read_env_from(&|var| match var {
+            "INGRESS_CLASS_NAME" => Some("from-env".to_string()),
+            "INGRESS_ANNOTATIONS" => Some(r#"{"shared": "env"}"#.to_string()),
+            _ => None,
+        });
+        assert_eq!(problems, Vec::<String>::new());
+        assert_eq!(config.ingress_class(&constants), "from-env");
+        // Replaced as a whole, not merged.
+        assert_eq!(
+            config.ingress_annotations(&constants),
+            BTreeMap::from([("shared".to_string(), "env".to_string())])
+        );
     }
 }
@@ -1,22 +1,53 @@
-use anyhow::bail;
+use anyhow::{anyhow, bail};
+use blake2::{digest::consts::U28, Blake2b, Digest};
+use futures::StreamExt;
+use hickory_resolver::{
+    config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
+    error::ResolveErrorKind,
+    TokioAsyncResolver,
+};
 use k8s_openapi::api::{
     apps::v1::Deployment,
-    core::v1::{ConfigMap, Service},
-    networking::v1::Ingress,
+    core::v1::{ConfigMap, Node, Pod, Secret, Service},
+    networking::v1::{Ingress, NetworkPolicy},
+    policy::v1::PodDisruptionBudget,
 };
+use k8s_openapi::chrono::{DateTime, Utc};
 use kube::{
-    api::{DeleteParams, ListParams, Patch, PatchParams},
-    runtime::controller::Action,
-    Api, Client, ResourceExt,
+    api::{DeleteParams, ListParams, Patch, PatchParams, PostParams},
+    runtime::{
+        controller::Action,
+        reflector::{self, store::Writer, ObjectRef, Store},
+        watcher, WatchStreamExt,
+    },
+    Api, Client, Resource, ResourceExt,
 };
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex, RwLock},
+    time::{Duration, Instant},
+};
 use thiserror::Error;
 use tracing::{error, info, warn};
 
-use crate::{config::Config, custom_resource::HydraDoomNodeStatus};
+use crate::{
+    auth::{generate_token, ROTATED_FOR_ANNOTATION, ROTATE_TOKEN_ANNOTATION},
+    config::{Config, ExposureMode},
+    custom_resource::{HydraDoomNodeReport, HydraDoomNodeStatus, PeerExposure, StateTransition},
+    gateway::HTTPRoute,
+    keys::{
+        enterprise_address, key_hash, read_text_envelope, KeyPair, HYDRA_VERIFICATION_KEY,
+        PAYMENT_ADDRESS,
+    },
+    overrides::apply_overrides,
+};
 
-use super::custom_resource::{HydraDoomNode, HYDRA_DOOM_NODE_FINALIZER};
+use super::custom_resource::{
+    HydraDoomNode, CONFIG_HASH_ANNOTATION, CONSTANTS_ANNOTATION, HYDRA_DOOM_NODE_FINALIZER,
+};
 
 pub enum HydraDoomNodeState {
     Offline,
@@ -24,6 +55,7 @@ pub enum HydraDoomNodeState {
     HeadIsInitializing,
     HeadIsOpen,
     Sleeping,
+    Unknown,
 }
 impl From<f64> for HydraDoomNodeState {
     fn from(value: f64) -> Self {
@@ -35,6 +67,23 @@ impl From<f64> for HydraDoomNodeState {
         }
     }
 }
+impl HydraDoomNodeState {
+    /// Whether the node is in a state the idle policy is allowed to act on.
+    /// Only Online nodes qualify: sleeping an open head would strand the
+    /// players and funds in it, and initializing heads (and any state we
+    /// cannot read) are left alone.
+    pub fn is_idle_eligible(state: &str) -> bool {
+        state == String::from(HydraDoomNodeState::Online)
+    }
+}
+impl HydraDoomNodeState {
+    /// Whether the hydra-node is running and answering, regardless of the head.
+    pub fn is_up(state: &str) -> bool {
+        state == String::from(HydraDoomNodeState::Online)
+            || state == String::from(HydraDoomNodeState::HeadIsInitializing)
+            || state == String::from(HydraDoomNodeState::HeadIsOpen)
+    }
+}
 impl From<HydraDoomNodeState> for String {
     fn from(val: HydraDoomNodeState) -> Self {
         match val {
@@ -43,10 +92,15 @@ impl From<HydraDoomNodeState> for String {
             HydraDoomNodeState::HeadIsInitializing => "HeadIsInitializing".to_string(),
             HydraDoomNodeState::HeadIsOpen => "HeadIsOpen".to_string(),
             HydraDoomNodeState::Sleeping => "Sleeping".to_string(),
+            HydraDoomNodeState::Unknown => "Unknown".to_string(),
         }
     }
 }
 
+/// Paths, ports and names baked into the generated workloads. Defaults can be
+/// overridden from a YAML or TOML settings file (see `K8sConstants::load`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct K8sConstants {
     pub config_dir: String,
     pub secret_dir: String,
@@ -54,10 +108,15 @@ pub struct K8sConstants {
     pub socket_path: String,
     pub initial_utxo_config_dir: String,
     pub data_dir: String,
+    pub hydra_key_dir: String,
     pub persistence_dir: String,
+    pub tmp_dir: String,
     pub node_port: i32,
     pub port: i32,
+    /// Unless `INGRESS_CLASS_NAME` is set; see `Config::ingress_class`.
     pub ingress_class_name: String,
+    /// Unless `INGRESS_ANNOTATIONS` is set, which replaces them; see
+    /// `Config::ingress_annotations`.
     pub ingress_annotations: BTreeMap<String, String>,
     pub metrics_port: i32,
     pub metrics_endpoint: String,
@@ -77,7 +136,9 @@ impl Default for K8sConstants {
             socket_path: "/ipc/socket".to_string(),
             initial_utxo_config_dir: "/etc/initial_utxo_config".to_string(),
             data_dir: "/var/data".to_string(),
+            hydra_key_dir: "/var/hydra".to_string(),
             persistence_dir: "/var/data/persistence".to_string(),
+            tmp_dir: "/tmp".to_string(),
             node_port: 5001,
             port: 4001,
             metrics_port: 8000,
@@ -114,31 +175,232 @@ impl Default for K8sConstants {
     }
 }
 
+impl K8sConstants {
+    /// Constants from `Config::settings_file`, or the defaults without one.
+    pub fn load(config: &Config) -> anyhow::Result<Self> {
+        match &config.settings_file {
+            Some(path) => Self::from_file(path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Reads a settings file, as TOML when it ends in `.toml` and as YAML
+    /// otherwise. Settings left out keep their defaults; unknown ones are
+    /// rejected.
+    pub fn from_file(path: &str) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read settings file {}: {}", path, err))?;
+        let constants: Self = if path.ends_with(".toml") {
+            toml::from_str(&contents)
+                .map_err(|err| anyhow!("Invalid settings file {}: {}", path, err))?
+        } else {
+            serde_yaml::from_str(&contents)
+                .map_err(|err| anyhow!("Invalid settings file {}: {}", path, err))?
+        };
+
+        let problems = constants.validate();
+        if !problems.is_empty() {
+            bail!("Invalid settings file {}: {}", path, problems.join("; "));
+        }
+        Ok(constants)
+    }
+
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = vec![];
+
+        for (name, path) in [
+            ("configDir", &self.config_dir),
+            ("secretDir", &self.secret_dir),
+            ("socketDir", &self.socket_dir),
+            ("socketPath", &self.socket_path),
+            ("initialUtxoConfigDir", &self.initial_utxo_config_dir),
+            ("dataDir", &self.data_dir),
+            ("hydraKeyDir", &self.hydra_key_dir),
+            ("persistenceDir", &self.persistence_dir),
+            ("tmpDir", &self.tmp_dir),
+            ("metricsEndpoint", &self.metrics_endpoint),
+        ] {
+            if !path.starts_with('/') {
+                problems.push(format!("{} must be an absolute path", name));
+            }
+        }
+        // Both live on volumes mounted at their parent dirs.
+        if !self
+            .socket_path
+            .starts_with(&format!("{}/", self.socket_dir))
+        {
+            problems.push("socketPath must be inside socketDir".to_string());
+        }
+        if !self
+            .persistence_dir
+            .starts_with(&format!("{}/", self.data_dir))
+        {
+            problems.push("persistenceDir must be inside dataDir".to_string());
+        }
+
+        let ports = [
+            ("nodePort", self.node_port),
+            ("port", self.port),
+            ("metricsPort", self.metrics_port),
+        ];
+        for (name, port) in ports {
+            if !(1..=65535).contains(&port) {
+                problems.push(format!("{} must be between 1 and 65535", name));
+            }
+        }
+        if ports[0].1 == ports[1].1 || ports[0].1 == ports[2].1 || ports[1].1 == ports[2].1 {
+            problems.push("nodePort, port and metricsPort must differ".to_string());
+        }
+
+        for (name, metric) in [
+            ("stateMetric", &self.state_metric),
+            ("transactionsMetric", &self.transactions_metric),
+        ] {
+            let valid = metric.chars().enumerate().all(|(i, c)| {
+                c.is_ascii_alphabetic() || c == '_' || c == ':' || (i > 0 && c.is_ascii_digit())
+            });
+            if metric.is_empty() || !valid {
+                problems.push(format!("{} is not a valid metric name", name));
+            }
+        }
+
+        for (name, value) in [
+            ("dmtrctlImage", &self.dmtrctl_image),
+            ("ingressClassName", &self.ingress_class_name),
+            ("storageClassName", &self.storage_class_name),
+        ] {
+            if value.is_empty() {
+                problems.push(format!("{} must not be empty", name));
+            }
+        }
+
+        problems
+    }
+}
+
+#[derive(Default)]
+struct CircuitBreaker {
+    failures: u32,
+    open_until: Option<Instant>,
+    /// When the breaker opened, to tell whether the pod became ready since.
+    opened_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Error)]
+enum ScrapeError {
+    #[error("metrics endpoint unreachable: {0}")]
+    Unreachable(String),
+    #[error("failed to scrape metrics: {0}")]
+    Failed(String),
+}
+
 pub struct K8sContext {
     pub client: Client,
-    pub config: Config,
+    /// Config from the environment, before `HydraDoomConfig` overrides.
+    pub base_config: Config,
+    /// Effective config, swapped whenever the `HydraDoomConfig` changes.
+    config: RwLock<Arc<Config>>,
     pub constants: K8sConstants,
+    http: reqwest::Client,
+    breakers: Mutex<HashMap<String, CircuitBreaker>>,
+    resolver: Option<TokioAsyncResolver>,
+    /// Hydra verification key (hex) and its hash per node, filled in by the
+    /// reconciler so the status loop does not have to read secrets.
+    verification_keys: Mutex<HashMap<String, (String, String)>>,
+    /// Address of the per-node Cardano payment key, same deal as above.
+    cardano_addresses: Mutex<HashMap<String, String>>,
+    /// Node pods and cluster nodes, kept up to date by `watch_pods`, so the
+    /// status loop does not have to list them on every pass.
+    pods: Store<Pod>,
+    nodes: Store<Node>,
+    /// Secrets and ConfigMaps with the `ROLL_LABEL`, fed by the watches that
+    /// trigger reconciles when they change.
+    secrets: Store<Secret>,
+    configmaps: Store<ConfigMap>,
 }
 
 impl K8sContext {
-    pub fn new(client: Client, config: Config) -> Self {
+    pub fn new(
+        client: Client,
+        config: Config,
+        constants: K8sConstants,
+        pods: Store<Pod>,
+        nodes: Store<Node>,
+        secrets: Store<Secret>,
+        configmaps: Store<ConfigMap>,
+    ) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(config.status_timeout)
+            .connect_timeout(config.status_timeout)
+            .pool_idle_timeout(Duration::from_secs(90))
+            .pool_max_idle_per_host(1)
+            .build()
+            .expect("Failed to build HTTP client");
+
+        let resolver = config.external_dns.then(|| match config.dns_nameserver {
+            Some(nameserver) => TokioAsyncResolver::tokio(
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_clear(
+                        &[nameserver.ip()],
+                        nameserver.port(),
+                        true,
+                    ),
+                ),
+                ResolverOpts::default(),
+            ),
+            None => TokioAsyncResolver::tokio_from_system_conf()
+                .expect("Failed to read system resolver configuration"),
+        });
+
         Self {
             client,
-            config,
-            constants: Default::default(),
+            config: RwLock::new(Arc::new(config.clone())),
+            base_config: config,
+            constants,
+            http,
+            breakers: Default::default(),
+            resolver,
+            verification_keys: Default::default(),
+            cardano_addresses: Default::default(),
+            pods,
+            nodes,
+            secrets,
+            configmaps,
         }
     }
 
+    pub fn config(&self) -> Arc<Config> {
+        self.config.read().unwrap().clone()
+    }
+
+    pub fn set_config(&self, config: Config) {
+        *self.config.write().unwrap() = Arc::new(config);
+    }
+
     pub async fn patch(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
         info!("Running patch");
+        // The config hash covers the node's configmap, so the deployment is
+        // only patched once the configmap is in place.
+        let patch_deployment = async {
+            let configmap = self.patch_configmap(crd).await?;
+            self.patch_deployment(crd, &configmap).await
+        };
         match tokio::join!(
-            self.patch_deployment(crd),
+            patch_deployment,
             self.patch_service(crd),
-            self.patch_ingress(crd),
-            self.patch_configmap(crd),
+            self.patch_peer_service(crd),
+            self.patch_token_secret(crd),
+            self.patch_credentials_secret(crd),
+            self.patch_hydra_keys(crd),
+            self.patch_cardano_keys(crd),
+            self.patch_network_policy(crd),
+            self.patch_pod_disruption_budget(crd),
+            self.patch_exposure(crd),
             self.patch_crd(crd)
         ) {
-            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_)) => (),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_)) => (),
             _ => bail!("Failed to apply patch for components."),
         };
 
@@ -149,10 +411,13 @@ impl K8sContext {
         match tokio::join!(
             self.remove_deployment(crd),
             self.remove_service(crd),
-            self.remove_ingress(crd),
+            self.remove_peer_service(crd),
+            self.remove_network_policy(crd),
+            self.remove_pod_disruption_budget(crd),
+            self.remove_exposure(crd),
             self.remove_configmap(crd),
         ) {
-            (Ok(_), Ok(_), Ok(_), Ok(_)) => Ok(()),
+            (Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_), Ok(_)) => Ok(()),
             _ => bail!("Failed to remove resources"),
         }
     }
@@ -166,7 +431,10 @@ impl K8sContext {
             &PatchParams::default(),
             &Patch::Merge(json!({
                 "metadata": {
-                    "finalizers": [HYDRA_DOOM_NODE_FINALIZER]
+                    "finalizers": [HYDRA_DOOM_NODE_FINALIZER],
+                    "annotations": {
+                        CONSTANTS_ANNOTATION: serde_json::to_string(&self.constants)?
+                    }
                 }
             })),
         )
@@ -179,12 +447,17 @@ impl K8sContext {
 
     async fn patch_configmap(&self, crd: &HydraDoomNode) -> anyhow::Result<ConfigMap> {
         let api: Api<ConfigMap> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let configmap = apply_overrides(
+            crd.configmap(&self.config(), &self.constants),
+            crd.configmap_overrides(),
+        )
+        .inspect_err(|err| error!(err = err.to_string(), "Invalid configmap override."))?;
 
         // Create or patch the configmap
         api.patch(
             &crd.internal_name(),
             &PatchParams::apply("hydra-doom-pod-controller"),
-            &Patch::Apply(&crd.configmap(&self.config, &self.constants)),
+            &Patch::Apply(&configmap),
         )
         .await
         .map_err(|err| {
@@ -204,14 +477,93 @@ impl K8sContext {
         }
     }
 
-    async fn patch_deployment(&self, crd: &HydraDoomNode) -> anyhow::Result<Deployment> {
+    async fn patch_deployment(
+        &self,
+        crd: &HydraDoomNode,
+        configmap: &ConfigMap,
+    ) -> anyhow::Result<Deployment> {
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let existing = api.get_opt(&crd.internal_name()).await?;
+
+        // Heads that are initializing or open keep the hash their pods were
+        // started with, unless configured otherwise, so a config change does
+        // not drop the head. Same as what the disruption budget protects.
+        // The constants snapshot goes along with the hash, so that it always
+        // tells what the running pods were started with.
+        let keep_pods = crd.disruption_protected() && !self.config().roll_open_heads;
+        let stamps = if keep_pods {
+            let existing_annotations = existing.as_ref().and_then(|deployment| {
+                deployment
+                    .spec
+                    .as_ref()?
+                    .template
+                    .metadata
+                    .as_ref()?
+                    .annotations
+                    .clone()
+            });
+            [CONFIG_HASH_ANNOTATION, CONSTANTS_ANNOTATION]
+                .into_iter()
+                .filter_map(|key| {
+                    let value = existing_annotations.as_ref()?.get(key)?;
+                    Some((key.to_string(), value.clone()))
+                })
+                .collect()
+        } else {
+            BTreeMap::from([
+                (
+                    CONFIG_HASH_ANNOTATION.to_string(),
+                    self.config_hash(crd, configmap).await?,
+                ),
+                (
+                    CONSTANTS_ANNOTATION.to_string(),
+                    serde_json::to_string(&self.constants)?,
+                ),
+            ])
+        };
+
+        let deployment = crd
+            .deployment(&self.config(), &self.constants)
+            .inspect_err(|err| {
+                error!(
+                    err = err.to_string(),
+                    "Invalid resources for {}.",
+                    crd.name_any()
+                )
+            })?;
+        let mut deployment = apply_overrides(deployment, crd.deployment_overrides())
+            .inspect_err(|err| error!(err = err.to_string(), "Invalid deployment override."))?;
+        let spec = deployment.spec.as_mut().unwrap();
+        let template_metadata = spec.template.metadata.get_or_insert_with(Default::default);
+        if !stamps.is_empty() {
+            template_metadata
+                .annotations
+                .get_or_insert_with(BTreeMap::new)
+                .extend(stamps);
+        }
+
+        // Deployment selectors are immutable, so deployments created with an
+        // older selector keep it, and their pods keep carrying its labels.
+        // Nodes from before `spec.scheduling.profile` was optional select on
+        // `run-on: fargate`, and stay on Fargate until they are recreated.
+        let existing_selector = existing
+            .and_then(|deployment| deployment.spec)
+            .and_then(|spec| spec.selector.match_labels);
+        if let Some(selector) = existing_selector {
+            if Some(&selector) != spec.selector.match_labels.as_ref() {
+                template_metadata
+                    .labels
+                    .get_or_insert_with(BTreeMap::new)
+                    .extend(selector.clone());
+                spec.selector.match_labels = Some(selector);
+            }
+        }
 
         // Create or patch the deployment
         api.patch(
             &crd.internal_name(),
             &PatchParams::apply("hydra-doom-pod-controller"),
-            &Patch::Apply(&crd.deployment(&self.config, &self.constants)),
+            &Patch::Apply(&deployment),
         )
         .await
         .map_err(|err| {
@@ -220,6 +572,51 @@ impl K8sContext {
         })
     }
 
+    /// Hash over the constants and the contents of every Secret and
+    /// ConfigMap the node's pod uses. Stamped on the pod template so that
+    /// changing any of them rolls the pods. Shared Secrets and ConfigMaps
+    /// come from the stores of the `ROLL_LABEL` watches; unlabelled ones
+    /// count as missing. The node's own ConfigMap is the one just applied.
+    async fn config_hash(
+        &self,
+        crd: &HydraDoomNode,
+        node_configmap: &ConfigMap,
+    ) -> anyhow::Result<String> {
+        let namespace = crd.namespace().unwrap();
+        // Before the first list an empty store would look like every source
+        // was removed, and roll all the pods.
+        self.secrets.wait_until_ready().await?;
+        self.configmaps.wait_until_ready().await?;
+
+        let mut contents = BTreeMap::from([(
+            "constants".to_string(),
+            serde_json::to_value(&self.constants)?,
+        )]);
+        for name in crd.referenced_secrets(&self.config()) {
+            let data = self
+                .secrets
+                .get(&ObjectRef::new(&name).within(&namespace))
+                .and_then(|secret| secret.data.clone());
+            contents.insert(format!("secret/{}", name), serde_json::to_value(data)?);
+        }
+        for name in crd.referenced_configmaps(&self.config()) {
+            let configmap = if name == node_configmap.name_any() {
+                Some(Arc::new(node_configmap.clone()))
+            } else {
+                self.configmaps
+                    .get(&ObjectRef::new(&name).within(&namespace))
+            };
+            let data = configmap.map(
+                |configmap| json!({ "data": configmap.data, "binaryData": configmap.binary_data }),
+            );
+            contents.insert(format!("configmap/{}", name), json!(data));
+        }
+
+        Ok(hex::encode(Blake2b::<U28>::digest(serde_json::to_vec(
+            &contents,
+        )?)))
+    }
+
     async fn remove_deployment(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
         let api: Api<Deployment> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
         let dp = DeleteParams::default();
@@ -234,11 +631,16 @@ impl K8sContext {
         // Apply the service to the cluster
         let services: Api<Service> =
             Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let service = apply_overrides(
+            crd.service(&self.config(), &self.constants),
+            crd.service_overrides(),
+        )
+        .inspect_err(|err| error!(err = err.to_string(), "Invalid service override."))?;
         services
             .patch(
                 &crd.internal_name(),
                 &PatchParams::apply("hydra-doom-pod-controller"),
-                &Patch::Apply(&crd.service(&self.config, &self.constants)),
+                &Patch::Apply(&service),
             )
             .await
             .map_err(|err| {
@@ -257,13 +659,386 @@ impl K8sContext {
         }
     }
 
+    /// Generates the node's hydra key pair once and keeps it in a secret owned
+    /// by the node. Referenced secrets are only read.
+    async fn patch_hydra_keys(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let name = crd.hydra_keys_secret_name();
+
+        let verification_key = match api.get_opt(&name).await? {
+            Some(secret) => {
+                let envelope = secret
+                    .data
+                    .and_then(|data| data.get(HYDRA_VERIFICATION_KEY).cloned())
+                    .ok_or(anyhow!("Secret {} has no {}", name, HYDRA_VERIFICATION_KEY))?;
+                read_text_envelope(&envelope.0)?
+            }
+            None if crd.spec.hydra_key_secret.is_some() => {
+                bail!("Referenced hydra key secret {} does not exist", name)
+            }
+            None => {
+                info!("Generating hydra keys for {}.", crd.name_any());
+                let keys = KeyPair::generate_hydra();
+                api.create(&PostParams::default(), &crd.hydra_keys_secret(&keys))
+                    .await
+                    .map_err(|err| {
+                        error!(err = err.to_string(), "Failed to create hydra keys secret.");
+                        anyhow::Error::from(err)
+                    })?;
+                keys.verification_key_bytes.to_vec()
+            }
+        };
+
+        self.verification_keys.lock().unwrap().insert(
+            format!("{}/{}", crd.namespace().unwrap(), crd.name_any()),
+            (
+                hex::encode(&verification_key),
+                hex::encode(key_hash(&verification_key)),
+            ),
+        );
+        Ok(())
+    }
+
+    /// Generates the node's own Cardano payment key the first time it is
+    /// needed. Like the hydra keys it is never rotated by the operator, as the
+    /// funds of an open head are locked to it.
+    async fn patch_cardano_keys(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        if !crd.per_node_cardano_keys() {
+            return Ok(());
+        }
+
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let name = crd.cardano_keys_secret_name(&self.config());
+
+        let address = match api.get_opt(&name).await? {
+            Some(secret) => secret
+                .data
+                .and_then(|data| data.get(PAYMENT_ADDRESS).cloned())
+                .ok_or(anyhow!("Secret {} has no {}", name, PAYMENT_ADDRESS))
+                .and_then(|address| Ok(String::from_utf8(address.0)?))?,
+            None => {
+                info!("Generating cardano keys for {}.", crd.name_any());
+                let keys = KeyPair::generate_payment();
+                let address = enterprise_address(
+                    &keys.verification_key_bytes,
+                    crd.spec.network_id.unwrap_or(0),
+                )?;
+                api.create(
+                    &PostParams::default(),
+                    &crd.cardano_keys_secret(&keys, &address, &self.config()),
+                )
+                .await
+                .map_err(|err| {
+                    error!(
+                        err = err.to_string(),
+                        "Failed to create cardano keys secret."
+                    );
+                    anyhow::Error::from(err)
+                })?;
+                address
+            }
+        };
+
+        self.cardano_addresses.lock().unwrap().insert(
+            format!("{}/{}", crd.namespace().unwrap(), crd.name_any()),
+            address,
+        );
+        Ok(())
+    }
+
+    async fn patch_credentials_secret(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let Some(secret) = crd.credentials_secret(&self.config()) else {
+            return Ok(());
+        };
+
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        api.patch(
+            &self.config().credentials_secret,
+            &PatchParams::apply("hydra-doom-pod-controller"),
+            &Patch::Apply(&secret),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            error!(
+                err = err.to_string(),
+                "Failed to create credentials secret."
+            );
+            err.into()
+        })
+    }
+
+    /// Makes sure the node has an access token, generating a new one when the
+    /// node's rotate annotation changed since the current token was issued.
+    async fn patch_token_secret(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        if self.config().auth_url.is_none() {
+            return Ok(());
+        }
+
+        let api: Api<Secret> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let rotation = crd
+            .annotations()
+            .get(ROTATE_TOKEN_ANNOTATION)
+            .cloned()
+            .unwrap_or_default();
+
+        let current = api.get_opt(&crd.token_secret_name()).await?;
+        let up_to_date = current.is_some_and(|secret| {
+            secret.annotations().get(ROTATED_FOR_ANNOTATION) == Some(&rotation)
+        });
+        if up_to_date {
+            return Ok(());
+        }
+
+        info!("Issuing access token for {}.", crd.name_any());
+        api.patch(
+            &crd.token_secret_name(),
+            &PatchParams::apply("hydra-doom-pod-controller").force(),
+            &Patch::Apply(&crd.token_secret(&generate_token(), &rotation)),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            error!(err = err.to_string(), "Failed to create token secret.");
+            err.into()
+        })
+    }
+
+    async fn patch_network_policy(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        if !self.config().network_policies {
+            return self.remove_network_policy(crd).await;
+        }
+
+        let api: Api<NetworkPolicy> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        api.patch(
+            &crd.internal_name(),
+            &PatchParams::apply("hydra-doom-pod-controller"),
+            &Patch::Apply(&crd.network_policy(&self.config(), &self.constants)),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            error!(err = err.to_string(), "Failed to create network policy.");
+            err.into()
+        })
+    }
+
+    async fn remove_network_policy(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let api: Api<NetworkPolicy> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let dp = DeleteParams::default();
+        match api.delete(&crd.internal_name(), &dp).await {
+            Ok(_) => Ok(()),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn patch_pod_disruption_budget(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let Some(pdb) = crd.pod_disruption_budget() else {
+            return self.remove_pod_disruption_budget(crd).await;
+        };
+
+        let api: Api<PodDisruptionBudget> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        api.patch(
+            &crd.internal_name(),
+            &PatchParams::apply("hydra-doom-pod-controller"),
+            &Patch::Apply(&pdb),
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| {
+            error!(
+                err = err.to_string(),
+                "Failed to create pod disruption budget."
+            );
+            err.into()
+        })
+    }
+
+    async fn remove_pod_disruption_budget(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let api: Api<PodDisruptionBudget> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let dp = DeleteParams::default();
+        match api.delete(&crd.internal_name(), &dp).await {
+            Ok(_) => Ok(()),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Kubernetes keeps no record of evictions refused by a disruption
+    /// budget, so this goes by the pod sitting on a cordoned node while the
+    /// node is protected, which is what a stuck drain looks like.
+    /// Pods of the node's deployment, from the pod store.
+    fn node_pods(&self, crd: &HydraDoomNode) -> Vec<Arc<Pod>> {
+        let namespace = crd.namespace();
+        let labels = crd.internal_labels();
+        self.pods
+            .state()
+            .into_iter()
+            .filter(|pod| {
+                pod.namespace() == namespace
+                    && labels
+                        .iter()
+                        .all(|(key, value)| pod.labels().get(key) == Some(value))
+            })
+            .collect()
+    }
+
+    /// When the node's pod last became Ready, if it is.
+    fn ready_since(&self, crd: &HydraDoomNode) -> Option<DateTime<Utc>> {
+        self.node_pods(crd)
+            .iter()
+            .filter_map(|pod| {
+                pod.status
+                    .as_ref()?
+                    .conditions
+                    .as_ref()?
+                    .iter()
+                    .find(|condition| condition.type_ == "Ready" && condition.status == "True")?
+                    .last_transition_time
+                    .as_ref()
+                    .map(|time| time.0)
+            })
+            .max()
+    }
+
+    fn check_eviction_blocked(
+        &self,
+        crd: &HydraDoomNode,
+        status: &HydraDoomNodeStatus,
+    ) -> Option<String> {
+        if !crd.disruption_protected_in(&status.state) {
+            return None;
+        }
+
+        self.node_pods(crd)
+            .iter()
+            .filter_map(|pod| pod.spec.as_ref()?.node_name.clone())
+            .find(|node_name| {
+                self.nodes
+                    .get(&ObjectRef::new(node_name))
+                    .and_then(|node| node.spec.as_ref()?.unschedulable)
+                    == Some(true)
+            })
+    }
+
+    async fn patch_peer_service(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let Some(service) = crd.peer_service(&self.config(), &self.constants) else {
+            return self.remove_peer_service(crd).await;
+        };
+
+        let services: Api<Service> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        services
+            .patch(
+                &crd.peer_service_name(),
+                &PatchParams::apply("hydra-doom-pod-controller"),
+                &Patch::Apply(&service),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|err| {
+                error!(err = err.to_string(), "Failed to create peer service.");
+                err.into()
+            })
+    }
+
+    async fn remove_peer_service(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let services: Api<Service> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let dp = DeleteParams::default();
+        match services.delete(&crd.peer_service_name(), &dp).await {
+            Ok(_) => Ok(()),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Whether the node's external host resolves yet. Only checked when the
+    /// operator manages DNS records through external-dns.
+    async fn check_dns(&self, crd: &HydraDoomNode) -> Option<bool> {
+        let resolver = self.resolver.as_ref()?;
+        let host = crd.external_host(&self.config(), &self.constants);
+        match resolver.lookup_ip(format!("{}.", host)).await {
+            Ok(lookup) => Some(lookup.iter().next().is_some()),
+            // Expected until external-dns has created the record.
+            Err(err) if matches!(err.kind(), ResolveErrorKind::NoRecordsFound { .. }) => {
+                Some(false)
+            }
+            Err(err) => {
+                warn!(err = err.to_string(), "Failed to resolve {}", host);
+                Some(false)
+            }
+        }
+    }
+
+    /// Address other parties can pass as `--peer` to reach this node, if the
+    /// peer port is exposed and the address is already known.
+    async fn get_peer_address(&self, crd: &HydraDoomNode) -> Option<String> {
+        let exposure = crd.spec.peer_exposure.clone().unwrap_or_default();
+        if exposure == PeerExposure::None {
+            return None;
+        }
+        if exposure == PeerExposure::ClusterIP {
+            return Some(format!(
+                "{}.{}.svc.cluster.local:{}",
+                crd.peer_service_name(),
+                crd.namespace().unwrap(),
+                self.constants.node_port
+            ));
+        }
+
+        let services: Api<Service> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let service = match services.get(&crd.peer_service_name()).await {
+            Ok(service) => service,
+            Err(err) => {
+                warn!(
+                    err = err.to_string(),
+                    "Failed to get peer service for {}",
+                    crd.name_any()
+                );
+                return None;
+            }
+        };
+
+        match exposure {
+            PeerExposure::NodePort => {
+                let node_port = service
+                    .spec?
+                    .ports?
+                    .into_iter()
+                    .find_map(|port| port.node_port)?;
+                // Node addresses are not something the operator can tell
+                // peers outside the cluster can reach; it has to be given.
+                let host = self.config().peer_node_address.clone()?;
+                Some(format!("{}:{}", host, node_port))
+            }
+            PeerExposure::LoadBalancer => {
+                let ingress = service.status?.load_balancer?.ingress?.into_iter().next()?;
+                let host = ingress.hostname.or(ingress.ip)?;
+                Some(format!("{}:{}", host, self.constants.node_port))
+            }
+            _ => None,
+        }
+    }
+
     async fn patch_ingress(&self, crd: &HydraDoomNode) -> anyhow::Result<Ingress> {
         // Apply the service to the cluster
         let api: Api<Ingress> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let ingress = apply_overrides(
+            crd.ingress(&self.config(), &self.constants),
+            crd.ingress_overrides(),
+        )
+        .inspect_err(|err| error!(err = err.to_string(), "Invalid ingress override."))?;
         api.patch(
             &crd.internal_name(),
             &PatchParams::apply("hydra-doom-pod-controller"),
-            &Patch::Apply(&crd.ingress(&self.config, &self.constants)),
+            &Patch::Apply(&ingress),
         )
         .await
         .map_err(|err| {
@@ -277,10 +1052,97 @@ impl K8sContext {
         let dp = DeleteParams::default();
         match api.delete(&crd.internal_name(), &dp).await {
             Ok(_) => Ok(()),
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn patch_http_route(&self, crd: &HydraDoomNode) -> anyhow::Result<HTTPRoute> {
+        let api: Api<HTTPRoute> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        api.patch(
+            &crd.internal_name(),
+            &PatchParams::apply("hydra-doom-pod-controller"),
+            &Patch::Apply(&crd.http_route(&self.config(), &self.constants)),
+        )
+        .await
+        .map_err(|err| {
+            error!(err = err.to_string(), "Failed to create HTTPRoute.");
+            err.into()
+        })
+    }
+
+    async fn remove_http_route(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let api: Api<HTTPRoute> = Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        let dp = DeleteParams::default();
+        match api.delete(&crd.internal_name(), &dp).await {
+            Ok(_) => Ok(()),
+            // Also covers clusters without the Gateway API CRDs installed.
+            Err(kube::Error::Api(err)) if err.code == 404 => Ok(()),
             Err(e) => Err(e.into()),
         }
     }
 
+    /// Creates the object of the selected exposure mode and removes the other
+    /// kind, in case the mode was switched.
+    async fn patch_exposure(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        match self.config().exposure_mode {
+            ExposureMode::Ingress => {
+                self.patch_ingress(crd).await?;
+                self.remove_http_route(crd).await
+            }
+            ExposureMode::Gateway => {
+                self.patch_http_route(crd).await?;
+                self.remove_ingress(crd).await
+            }
+        }
+    }
+
+    async fn remove_exposure(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        self.remove_ingress(crd).await?;
+        self.remove_http_route(crd).await
+    }
+
+    async fn scrape_metrics(
+        &self,
+        url: &str,
+    ) -> Result<(Option<HydraDoomNodeState>, Option<i64>), ScrapeError> {
+        let response = self.http.get(url).send().await.map_err(|err| {
+            if err.is_connect() {
+                ScrapeError::Unreachable(err.to_string())
+            } else {
+                ScrapeError::Failed(err.to_string())
+            }
+        })?;
+        let body = response
+            .text()
+            .await
+            .map_err(|err| ScrapeError::Failed(err.to_string()))?;
+
+        let lines: Vec<_> = body.lines().map(|s| Ok(s.to_owned())).collect();
+        let metrics = prometheus_parse::Scrape::parse(lines.into_iter())
+            .map_err(|err| ScrapeError::Failed(err.to_string()))?;
+
+        let state = metrics
+            .samples
+            .iter()
+            .find(|sample| sample.metric == self.constants.state_metric)
+            .map(|sample| match sample.value {
+                prometheus_parse::Value::Gauge(value) => HydraDoomNodeState::from(value),
+                _ => HydraDoomNodeState::Offline,
+            });
+
+        let transactions = metrics
+            .samples
+            .iter()
+            .find(|sample| sample.metric == self.constants.transactions_metric)
+            .map(|sample| match sample.value {
+                prometheus_parse::Value::Counter(count) => count.round() as i64,
+                _ => 0,
+            });
+
+        Ok((state, transactions))
+    }
+
     async fn get_status_from_crd(&self, crd: &HydraDoomNode) -> HydraDoomNodeStatus {
         let url = format!(
             "http://{}:{}{}",
@@ -289,112 +1151,359 @@ impl K8sContext {
             self.constants.metrics_endpoint
         );
 
+        let key = format!("{}/{}", crd.namespace().unwrap(), crd.name_any());
         if crd.spec.asleep.unwrap_or(false) {
+            // Failures from before the node went to sleep must not delay
+            // noticing that it woke up.
+            self.breakers.lock().unwrap().remove(&key);
             return HydraDoomNodeStatus {
                 state: HydraDoomNodeState::Sleeping.into(),
                 transactions: 0,
-                local_url: format!("ws://{}:{}", crd.internal_host(), self.constants.port),
-                external_url: format!(
-                    "ws://{}:{}",
-                    crd.external_host(&self.config, &self.constants),
-                    self.config.external_port
-                ),
+                local_url: crd.local_url(&self.constants),
+                external_url: crd.external_url(&self.config(), &self.constants),
+                ..Default::default()
             };
         }
 
-        let default = HydraDoomNodeStatus::offline(crd, &self.config, &self.constants);
-
-        match reqwest::get(&url).await {
-            Ok(response) => match response.text().await {
-                Ok(body) => {
-                    let lines: Vec<_> = body.lines().map(|s| Ok(s.to_owned())).collect();
-                    match prometheus_parse::Scrape::parse(lines.into_iter()) {
-                        Ok(metrics) => {
-                            let state = metrics
-                                .clone()
-                                .samples
-                                .into_iter()
-                                .find(|sample| sample.metric == self.constants.state_metric)
-                                .map(|sample| match sample.value {
-                                    prometheus_parse::Value::Gauge(value) => {
-                                        HydraDoomNodeState::from(value)
-                                    }
-                                    _ => HydraDoomNodeState::Offline,
-                                });
-
-                            let transactions = metrics
-                                .clone()
-                                .samples
-                                .into_iter()
-                                .find(|sample| sample.metric == self.constants.transactions_metric)
-                                .map(|sample| match sample.value {
-                                    prometheus_parse::Value::Counter(count) => count.round() as i64,
-                                    _ => 0,
-                                });
-
-                            match (state, transactions) {
-                                (Some(state), Some(transactions)) => HydraDoomNodeStatus {
-                                    transactions,
-                                    state: state.into(),
-                                    local_url: format!(
-                                        "ws://{}:{}",
-                                        crd.internal_host(),
-                                        self.constants.port
-                                    ),
-                                    external_url: format!(
-                                        "ws://{}:{}",
-                                        crd.external_host(&self.config, &self.constants),
-                                        self.config.external_port
-                                    ),
-                                },
-                                _ => default,
-                            }
-                        }
-                        Err(err) => {
-                            warn!(
-                                err = err.to_string(),
-                                "Failed to parse metrics for {}",
-                                crd.name_any()
-                            );
-                            default
-                        }
-                    }
-                }
-                Err(err) => {
-                    warn!(
-                        err = err.to_string(),
-                        "Failed to parse request response to metrics endpoint for {}",
-                        crd.name_any()
-                    );
-                    default
+        let default = HydraDoomNodeStatus::offline(crd, &self.config(), &self.constants);
+        // Nothing was learned about the node, so keep the counter where it
+        // was rather than making it look like it restarted.
+        let unknown = HydraDoomNodeStatus {
+            state: HydraDoomNodeState::Unknown.into(),
+            transactions: crd
+                .status
+                .as_ref()
+                .map(|status| status.transactions)
+                .unwrap_or_default(),
+            ..default.clone()
+        };
+
+        if self.breaker_is_open(&key, self.ready_since(crd)) {
+            return unknown;
+        }
+
+        match self.scrape_metrics(&url).await {
+            Ok((Some(state), Some(transactions))) => {
+                self.record_scrape(&key, true);
+                HydraDoomNodeStatus {
+                    transactions,
+                    state: state.into(),
+                    ..default
                 }
-            },
+            }
+            Ok(_) => {
+                self.record_scrape(&key, true);
+                default
+            }
+            // A refused or timed out connection is as likely an overloaded
+            // sidecar as a stopped node, so it says nothing about the state.
             Err(err) => {
+                self.record_scrape(&key, false);
                 warn!(
                     err = err.to_string(),
-                    "Failed to request metrics for {}",
+                    "Failed to scrape metrics for {}",
                     crd.name_any()
                 );
-                default
+                unknown
             }
         }
     }
 
+    /// Whether scrapes of the node are currently skipped. A pod that became
+    /// Ready after the breaker opened closes it again, so a node that was
+    /// just started is not left reporting Unknown for the whole cooldown.
+    fn breaker_is_open(&self, key: &str, ready_since: Option<DateTime<Utc>>) -> bool {
+        let mut breakers = self.breakers.lock().unwrap();
+        let Some(breaker) = breakers.get(key) else {
+            return false;
+        };
+        if ready_since.is_some_and(|ready| breaker.opened_at.is_some_and(|at| ready > at)) {
+            breakers.remove(key);
+            return false;
+        }
+        breaker
+            .open_until
+            .is_some_and(|open_until| Instant::now() < open_until)
+    }
+
+    fn record_scrape(&self, key: &str, success: bool) {
+        let mut breakers = self.breakers.lock().unwrap();
+        if success {
+            breakers.remove(key);
+            return;
+        }
+
+        let breaker = breakers.entry(key.to_string()).or_default();
+        breaker.failures += 1;
+        if breaker.failures >= self.config().status_breaker_threshold {
+            warn!(
+                "Opening status circuit breaker for {} after {} failures.",
+                key, breaker.failures
+            );
+            breaker.open_until = Some(Instant::now() + self.config().status_breaker_cooldown);
+            breaker.opened_at = Some(Utc::now());
+        }
+    }
+
+    /// Carries the lifetime statistics over from the previous status and
+    /// updates them with the newly observed one.
+    fn track_lifetime(
+        &self,
+        crd: &HydraDoomNode,
+        mut status: HydraDoomNodeStatus,
+    ) -> HydraDoomNodeStatus {
+        let previous = crd.status.clone().unwrap_or_default();
+        let now = Utc::now();
+
+        let mut uptime_seconds = previous.uptime_seconds.unwrap_or(0);
+        if HydraDoomNodeState::is_up(&previous.state) {
+            if let Some(observed_at) = previous
+                .observed_at
+                .as_deref()
+                .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            {
+                uptime_seconds +=
+                    (now - observed_at.with_timezone(&Utc)).num_seconds().max(0) as u64;
+            }
+        }
+
+        // The node's counter restarts from zero with the pod, so only count
+        // what moved since the last value we saw from a running node.
+        let mut total_transactions = previous.total_transactions.unwrap_or(0);
+        let mut counted_transactions = previous.counted_transactions.unwrap_or(0);
+        if HydraDoomNodeState::is_up(&status.state) {
+            total_transactions += if status.transactions >= counted_transactions {
+                status.transactions - counted_transactions
+            } else {
+                status.transactions
+            };
+            counted_transactions = status.transactions;
+        }
+
+        let mut transitions = previous.transitions.unwrap_or_default();
+        if previous.state != status.state {
+            transitions.push(StateTransition {
+                state: status.state.clone(),
+                at: now.to_rfc3339(),
+            });
+            if transitions.len() > MAX_STATE_TRANSITIONS {
+                transitions.drain(..transitions.len() - MAX_STATE_TRANSITIONS);
+            }
+        }
+
+        status.opened_at = previous.opened_at.or_else(|| {
+            (status.state == String::from(HydraDoomNodeState::HeadIsOpen)).then(|| now.to_rfc3339())
+        });
+        status.observed_at = Some(now.to_rfc3339());
+        status.uptime_seconds = Some(uptime_seconds);
+        status.total_transactions = Some(total_transactions);
+        status.counted_transactions = Some(counted_transactions);
+        status.transitions = Some(transitions);
+        status
+    }
+
+    /// Records the node's lifetime before its resources are torn down, either
+    /// as a `HydraDoomNodeReport` object or as a JSON file in the report dir.
+    pub async fn write_report(&self, crd: &HydraDoomNode) -> anyhow::Result<()> {
+        let report = crd.report();
+
+        if let Some(report_dir) = &self.config().report_dir {
+            let path = std::path::Path::new(report_dir).join(format!(
+                "{}-{}.json",
+                crd.namespace().unwrap(),
+                report.name_any()
+            ));
+            tokio::fs::write(&path, serde_json::to_vec_pretty(&report.spec)?)
+                .await
+                .map_err(|err| {
+                    error!(err = err.to_string(), "Failed to write report file.");
+                    anyhow::Error::from(err)
+                })?;
+            info!("Wrote report for {} to {}.", crd.name_any(), path.display());
+            return Ok(());
+        }
+
+        let api: Api<HydraDoomNodeReport> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        match api.create(&PostParams::default(), &report).await {
+            Ok(_) => {
+                info!("Created report {}.", report.name_any());
+                Ok(())
+            }
+            // A retried finalizer already recorded this run.
+            Err(kube::Error::Api(err)) if err.code == 409 => Ok(()),
+            Err(err) => {
+                error!(err = err.to_string(), "Failed to create report.");
+                Err(err.into())
+            }
+        }
+    }
+
+    async fn apply_idle_policy(
+        &self,
+        crd: &HydraDoomNode,
+        mut status: HydraDoomNodeStatus,
+    ) -> HydraDoomNodeStatus {
+        let previous = crd.status.clone().unwrap_or_default();
+
+        if crd.spec.asleep.unwrap_or(false) {
+            status.sleep_reason = previous.sleep_reason;
+            return status;
+        }
+
+        // Without a scrape there is no telling whether the node was active.
+        if status.state == String::from(HydraDoomNodeState::Unknown) {
+            status.last_activity_at = previous.last_activity_at;
+            return status;
+        }
+
+        let Some(policy) = &crd.spec.idle_policy else {
+            return status;
+        };
+
+        let now = Utc::now();
+        let last_activity_at = previous
+            .last_activity_at
+            .as_deref()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc));
+
+        // Any movement of the counter (or a state the policy does not cover)
+        // counts as activity and restarts the idle window.
+        let last_activity_at = match last_activity_at {
+            Some(at)
+                if status.transactions == previous.transactions
+                    && HydraDoomNodeState::is_idle_eligible(&status.state) =>
+            {
+                at
+            }
+            _ => now,
+        };
+        status.last_activity_at = Some(last_activity_at.to_rfc3339());
+
+        let idle_for = (now - last_activity_at).num_seconds().max(0) as u64;
+        if idle_for < policy.idle_seconds {
+            return status;
+        }
+
+        let api: Api<HydraDoomNode> =
+            Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+        match api
+            .patch(
+                &crd.name_any(),
+                &PatchParams::default(),
+                &Patch::Merge(json!({ "spec": { "asleep": true } })),
+            )
+            .await
+        {
+            Ok(_) => {
+                info!(
+                    "Putting {} to sleep after {}s idle.",
+                    crd.name_any(),
+                    idle_for
+                );
+                status.state = HydraDoomNodeState::Sleeping.into();
+                status.sleep_reason = Some(format!(
+                    "No transactions for {}s (idle policy: {}s)",
+                    idle_for, policy.idle_seconds
+                ));
+            }
+            Err(err) => {
+                warn!(
+                    err = err.to_string(),
+                    "Failed to put {} to sleep.",
+                    crd.name_any()
+                );
+            }
+        }
+
+        status
+    }
+
+    async fn build_status(&self, crd: &HydraDoomNode) -> HydraDoomNodeStatus {
+        let status = self.get_status_from_crd(crd).await;
+        let status = self.track_lifetime(crd, status);
+        let mut status = self.apply_idle_policy(crd, status).await;
+        status.peer_address = self.get_peer_address(crd).await;
+        let key = format!("{}/{}", crd.namespace().unwrap(), crd.name_any());
+        match self.verification_keys.lock().unwrap().get(&key) {
+            Some((verification_key, hash)) => {
+                status.hydra_verification_key = Some(verification_key.clone());
+                status.hydra_verification_key_hash = Some(hash.clone());
+            }
+            None => {
+                let previous = crd.status.clone().unwrap_or_default();
+                status.hydra_verification_key = previous.hydra_verification_key;
+                status.hydra_verification_key_hash = previous.hydra_verification_key_hash;
+            }
+        }
+        status.cardano_address = if crd.per_node_cardano_keys() {
+            self.cardano_addresses
+                .lock()
+                .unwrap()
+                .get(&key)
+                .cloned()
+                .or(crd.status.as_ref().and_then(|s| s.cardano_address.clone()))
+        } else {
+            Some(self.config().admin_addr.clone())
+        };
+        status.eviction_blocked_node = self.check_eviction_blocked(crd, &status);
+        status.eviction_blocked_at = match &status.eviction_blocked_node {
+            Some(node) => {
+                let previous = crd.status.clone().unwrap_or_default();
+                match previous.eviction_blocked_at {
+                    Some(at) if previous.eviction_blocked_node.as_ref() == Some(node) => Some(at),
+                    _ => {
+                        warn!(
+                            "Eviction of {} from cordoned node {} is blocked by its open head.",
+                            crd.name_any(),
+                            node
+                        );
+                        Some(Utc::now().to_rfc3339())
+                    }
+                }
+            }
+            None => None,
+        };
+        status.dns_ready = self.check_dns(crd).await;
+        if status.dns_ready == Some(false) {
+            // Do not advertise a URL clients cannot reach yet.
+            status.external_url = String::new();
+        }
+        status.spec_error = crd.validate_resources().err().map(|err| err.to_string());
+        status.access_token_secret = self
+            .config()
+            .auth_url
+            .as_ref()
+            .map(|_| crd.token_secret_name());
+        status
+    }
+
     async fn patch_statuses(&self) -> anyhow::Result<()> {
         let api: Api<HydraDoomNode> = Api::default_namespaced(self.client.clone());
         let crds = api.list(&ListParams::default()).await?;
 
-        let mut awaitables = vec![];
-        for crd in &crds {
-            awaitables.push(async {
+        // Forget the breakers of deleted nodes.
+        let keys: HashSet<String> = crds
+            .iter()
+            .map(|crd| format!("{}/{}", crd.namespace().unwrap(), crd.name_any()))
+            .collect();
+        self.breakers
+            .lock()
+            .unwrap()
+            .retain(|key, _| keys.contains(key));
+
+        futures::stream::iter(&crds)
+            .map(|crd| async move {
                 let name = crd.name_any();
                 let api: Api<HydraDoomNode> =
                     Api::namespaced(self.client.clone(), &crd.namespace().unwrap());
+                let status = self.build_status(crd).await;
                 if let Err(err) = api
                     .patch_status(
                         &name,
                         &PatchParams::default(),
-                        &Patch::Merge(json!({ "status": self.get_status_from_crd(crd).await })),
+                        &Patch::Merge(json!({ "status": status })),
                     )
                     .await
                 {
@@ -404,20 +1513,26 @@ impl K8sContext {
                     );
                 };
             })
-        }
-
-        futures::future::join_all(awaitables).await;
+            .buffer_unordered(self.config().status_concurrency)
+            .collect::<Vec<_>>()
+            .await;
 
         Ok(())
     }
 }
 
+const MAX_STATE_TRANSITIONS: usize = 50;
+
 pub async fn patch_statuses(context: Arc<K8sContext>) -> Result<()> {
     info!("Running status patcher loop.");
 
     loop {
         context.patch_statuses().await?;
-        tokio::time::sleep(Duration::from_secs(5)).await;
+        // Jitter the interval so that scrapes do not line up with other
+        // periodic work in the cluster.
+        let interval = context.config().status_interval.as_millis() as u64;
+        let jitter = rand::thread_rng().gen_range(0..=interval / 5);
+        tokio::time::sleep(Duration::from_millis(interval - interval / 10 + jitter)).await;
     }
 }
 
@@ -446,6 +1561,8 @@ pub async fn reconcile(crd: Arc<HydraDoomNode>, ctx: Arc<K8sContext>) -> Result<
             .finalizers()
             .contains(&HYDRA_DOOM_NODE_FINALIZER.to_string())
         {
+            // Keep a record of the run before its resources go away
+            ctx.write_report(&crd).await?;
             // Delete associated resources
             ctx.delete(&crd).await?;
             // Remove finalizer
@@ -466,11 +1583,90 @@ pub async fn reconcile(crd: Arc<HydraDoomNode>, ctx: Arc<K8sContext>) -> Result<
         return Ok(Action::await_change());
     }
 
+    // Do not roll out nodes from a config that is missing settings.
+    let problems = ctx.config().validate();
+    if !problems.is_empty() {
+        return Err(anyhow!("Invalid operator config: {}", problems.join("; ")).into());
+    }
+    if crd.spec.peer_exposure == Some(PeerExposure::NodePort)
+        && ctx.config().peer_node_address.is_none()
+    {
+        return Err(anyhow!(
+            "PEER_NODE_ADDRESS is required to expose the peer port of {} over NodePort",
+            crd.name_any()
+        )
+        .into());
+    }
+
     // Ensure finalizer is set
     ctx.patch(&crd).await?;
     Ok(Action::await_change())
 }
 
+/// Keeps the context's pod and node stores in sync. Only node pods are
+/// watched, and nodes without their status, which is most of their size.
+pub async fn watch_pods(client: Client, pods: Writer<Pod>, nodes: Writer<Node>) {
+    let pod_api: Api<Pod> = Api::default_namespaced(client.clone());
+    let pod_events = reflector::reflector(
+        pods,
+        watcher(
+            pod_api,
+            watcher::Config::default().labels("component=hydra-doom-node"),
+        ),
+    )
+    .default_backoff()
+    .touched_objects()
+    .map(|event| event.map(|_| ()));
+    let node_api: Api<Node> = Api::all(client);
+    let node_events = reflector::reflector(
+        nodes,
+        watcher(node_api, watcher::Config::default()).modify(|node| {
+            node.status = None;
+            node.managed_fields_mut().clear();
+        }),
+    )
+    .default_backoff()
+    .touched_objects()
+    .map(|event| event.map(|_| ()));
+
+    futures::stream::select(pod_events, node_events)
+        .for_each(|event| async move {
+            if let Err(err) = event {
+                warn!(err = err.to_string(), "Pod or node watcher error.");
+            }
+        })
+        .await
+}
+
+/// Only Secrets and ConfigMaps with this label set to "true" are watched, so
+/// that the operator does not cache every one in the namespace. Changing an
+/// unlabelled one does not roll the nodes using it.
+pub static ROLL_LABEL: &str = "hydra.doom/roll-nodes";
+
+/// Nodes in the store that use the given Secret or ConfigMap, to be
+/// reconciled again (and get a new config hash) when it changes.
+pub fn nodes_referencing<K: Resource<DynamicType = ()>>(
+    store: &Store<HydraDoomNode>,
+    config: &Config,
+    object: &K,
+) -> Vec<ObjectRef<HydraDoomNode>> {
+    let name = object.name_any();
+    let is_secret = K::kind(&()) == "Secret";
+    store
+        .state()
+        .into_iter()
+        .filter(|crd| crd.namespace() == object.namespace())
+        .filter(|crd| {
+            if is_secret {
+                crd.referenced_secrets(config).contains(&name)
+            } else {
+                crd.referenced_configmaps(config).contains(&name)
+            }
+        })
+        .map(|crd| ObjectRef::from_obj(crd.as_ref()))
+        .collect()
+}
+
 pub fn error_policy(crd: Arc<HydraDoomNode>, err: &Error, _ctx: Arc<K8sContext>) -> Action {
     error!(
         error = err.to_string(),
@@ -479,3 +1675,90 @@ pub fn error_policy(crd: Arc<HydraDoomNode>, err: &Error, _ctx: Arc<K8sContext>)
     );
     Action::requeue(Duration::from_secs(5))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_file(name: &str, contents: &str) -> String {
+        let path = std::env::temp_dir().join(format!("{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn only_online_nodes_are_idle_eligible() {
+        for (state, eligible) in [
+            (HydraDoomNodeState::Online, true),
+            (HydraDoomNodeState::HeadIsOpen, false),
+            (HydraDoomNodeState::HeadIsInitializing, false),
+            (HydraDoomNodeState::Offline, false),
+            (HydraDoomNodeState::Sleeping, false),
+        ] {
+            assert_eq!(
+                HydraDoomNodeState::is_idle_eligible(&String::from(state)),
+                eligible
+            );
+        }
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(K8sConstants::default().validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn rejects_invalid_constants() {
+        let constants = K8sConstants {
+            config_dir: "etc/config".to_string(),
+            socket_path: "/var/socket".to_string(),
+            persistence_dir: "/var/persistence".to_string(),
+            metrics_port: 4001,
+            node_port: 70000,
+            state_metric: "1_state".to_string(),
+            transactions_metric: "hydra-transactions".to_string(),
+            dmtrctl_image: String::new(),
+            ..Default::default()
+        };
+        assert_eq!(
+            constants.validate(),
+            vec![
+                "configDir must be an absolute path",
+                "socketPath must be inside socketDir",
+                "persistenceDir must be inside dataDir",
+                "nodePort must be between 1 and 65535",
+                "nodePort, port and metricsPort must differ",
+                "stateMetric is not a valid metric name",
+                "transactionsMetric is not a valid metric name",
+                "dmtrctlImage must not be empty",
+            ]
+        );
+    }
+
+    #[test]
+    fn reads_yaml_and_toml_settings() {
+        let yaml = settings_file("settings.yaml", "port: 4002\nstorageClassName: gp3\n");
+        let constants = K8sConstants::from_file(&yaml).unwrap();
+        assert_eq!(constants.port, 4002);
+        assert_eq!(constants.storage_class_name, "gp3");
+        assert_eq!(constants.node_port, K8sConstants::default().node_port);
+
+        let toml = settings_file("settings.toml", "metricsPort = 9000\n");
+        let constants = K8sConstants::from_file(&toml).unwrap();
+        assert_eq!(constants.metrics_port, 9000);
+    }
+
+    #[test]
+    fn rejects_unknown_and_invalid_settings() {
+        let unknown = settings_file("unknown.yaml", "prot: 4002\n");
+        assert!(K8sConstants::from_file(&unknown).is_err());
+
+        let invalid = settings_file("invalid.yaml", "port: 5001\n");
+        let err = K8sConstants::from_file(&invalid).unwrap_err().to_string();
+        assert!(
+            err.contains("nodePort, port and metricsPort must differ"),
+            "{}",
+            err
+        );
+    }
+}
@@ -1,72 +1,252 @@
+use anyhow::{anyhow, bail};
 use k8s_openapi::{
     api::{
         apps::v1::{Deployment, DeploymentSpec},
         core::v1::{
-            ConfigMap, ConfigMapVolumeSource, Container, ContainerPort, EmptyDirVolumeSource,
-            PodSpec, PodTemplateSpec, ResourceRequirements, SecretVolumeSource, Service,
-            ServicePort, ServiceSpec, Volume, VolumeMount,
+            Affinity, Capabilities, ConfigMap, ConfigMapVolumeSource, Container, ContainerPort,
+            EmptyDirVolumeSource, EnvVar, EnvVarSource, HTTPGetAction, PodSecurityContext, PodSpec,
+            PodTemplateSpec, Probe, ResourceRequirements, SeccompProfile, Secret,
+            SecretKeySelector, SecretVolumeSource, SecurityContext, Service, ServicePort,
+            ServiceSpec, TCPSocketAction, Toleration, TopologySpreadConstraint, Volume,
+            VolumeMount,
         },
         networking::v1::{
-            HTTPIngressPath, HTTPIngressRuleValue, Ingress, IngressBackend, IngressRule,
-            IngressServiceBackend, IngressSpec, ServiceBackendPort,
+            HTTPIngressPath, HTTPIngressRuleValue, IPBlock, Ingress, IngressBackend, IngressRule,
+            IngressServiceBackend, IngressSpec, IngressTLS, NetworkPolicy, NetworkPolicyEgressRule,
+            NetworkPolicyIngressRule, NetworkPolicyPeer, NetworkPolicyPort, NetworkPolicySpec,
+            ServiceBackendPort,
         },
+        policy::v1::{PodDisruptionBudget, PodDisruptionBudgetSpec},
     },
-    apimachinery::pkg::api::resource::Quantity,
+    apimachinery::pkg::{
+        api::resource::Quantity,
+        apis::meta::v1::{Condition, LabelSelector},
+        util::intstr::IntOrString,
+    },
+    ByteString,
+};
+use k8s_openapi::{
+    apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+        CustomResourceDefinition, ValidationRule,
+    },
+    chrono::{DateTime, Utc},
 };
-use kube::{api::ObjectMeta, CustomResource, ResourceExt};
+use kube::{api::ObjectMeta, CustomResource, CustomResourceExt, Resource, ResourceExt};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use crate::config::Config;
+use crate::{
+    auth::{ROTATED_FOR_ANNOTATION, TOKEN_KEY},
+    config::{Config, SecretKeyRef, TlsMode},
+    gateway::{
+        HTTPBackendRef, HTTPPathMatch, HTTPPathModifier, HTTPRoute, HTTPRouteFilter,
+        HTTPRouteMatch, HTTPRouteRule, HTTPRouteSpec, HTTPRouteTimeouts, HTTPURLRewriteFilter,
+        ParentReference,
+    },
+    keys::{
+        KeyPair, HYDRA_SIGNING_KEY, HYDRA_VERIFICATION_KEY, PAYMENT_ADDRESS, PAYMENT_SIGNING_KEY,
+        PAYMENT_VERIFICATION_KEY,
+    },
+    overrides::Overrides,
+};
 
-use super::controller::K8sConstants;
+use super::controller::{HydraDoomNodeState, K8sConstants, ROLL_LABEL};
 
 pub static HYDRA_DOOM_NODE_FINALIZER: &str = "hydradoomnode/finalizer";
+pub static HEAD_LABEL: &str = "hydra-doom-head";
+pub static CONFIG_HASH_ANNOTATION: &str = "hydra.doom/config-hash";
+/// Snapshot of the `K8sConstants` the node was last reconciled with. The pod
+/// template carries the snapshot its pods were started with, which differs
+/// while an initializing or open head keeps its pods.
+pub static CONSTANTS_ANNOTATION: &str = "hydra.doom/constants";
+/// Names the node a per-node Cardano key secret was generated for. The
+/// secrets outlive their nodes, so this is how leftovers are found.
+pub static CARDANO_KEYS_LABEL: &str = "hydra.doom/cardano-keys-for";
 
-#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
+/// Env var read from a secret, so that credentials never show up in the pod
+/// spec. Container args refer to it as `$(NAME)`.
+fn secret_env(name: &str, reference: &SecretKeyRef) -> EnvVar {
+    EnvVar {
+        name: name.to_string(),
+        value_from: Some(EnvVarSource {
+            secret_key_ref: Some(SecretKeySelector {
+                name: reference.name.clone(),
+                key: reference.key.clone(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Numeric value of a quantity such as `500m`, `2` or `4Gi`.
+pub fn parse_quantity(quantity: &str) -> anyhow::Result<f64> {
+    let split = quantity
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(quantity.len());
+    let (number, suffix) = quantity.split_at(split);
+    let number: f64 = number
+        .parse()
+        .map_err(|_| anyhow!("Invalid quantity {}", quantity))?;
+    let multiplier = match suffix {
+        "" => 1.0,
+        "n" => 1e-9,
+        "u" => 1e-6,
+        "m" => 1e-3,
+        "k" => 1e3,
+        "M" => 1e6,
+        "G" => 1e9,
+        "T" => 1e12,
+        "P" => 1e15,
+        "E" => 1e18,
+        "Ki" => 1024f64,
+        "Mi" => 1024f64.powi(2),
+        "Gi" => 1024f64.powi(3),
+        "Ti" => 1024f64.powi(4),
+        "Pi" => 1024f64.powi(5),
+        "Ei" => 1024f64.powi(6),
+        _ => match suffix.strip_prefix(['e', 'E']) {
+            Some(exponent) => 10f64.powi(
+                exponent
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid quantity {}", quantity))?,
+            ),
+            None => bail!("Invalid quantity {}", quantity),
+        },
+    };
+    Ok(number * multiplier)
+}
+
+/// Quantities are checked against the same pattern the API server uses for
+/// `resource.Quantity`.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
 pub struct ResourcesInner {
-    pub cpu: String,
-    pub memory: String,
+    #[schemars(regex(
+        pattern = r"^(\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))))?$"
+    ))]
+    pub cpu: Option<String>,
+    #[schemars(regex(
+        pattern = r"^(\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))))?$"
+    ))]
+    pub memory: Option<String>,
+}
+impl ResourcesInner {
+    /// Fields set here win over the ones in `base`.
+    fn or(&self, base: ResourcesInner) -> ResourcesInner {
+        ResourcesInner {
+            cpu: self.cpu.clone().or(base.cpu),
+            memory: self.memory.clone().or(base.memory),
+        }
+    }
 }
 impl From<&ResourcesInner> for BTreeMap<String, Quantity> {
     fn from(value: &ResourcesInner) -> Self {
-        BTreeMap::from([
-            ("cpu".to_string(), Quantity(value.cpu.clone())),
-            ("memory".to_string(), Quantity(value.memory.clone())),
-        ])
+        [("cpu", &value.cpu), ("memory", &value.memory)]
+            .into_iter()
+            .filter_map(|(name, quantity)| {
+                quantity
+                    .clone()
+                    .map(|quantity| (name.to_string(), Quantity(quantity)))
+            })
+            .collect()
     }
 }
 
-#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
-pub struct Resources {
-    pub requests: ResourcesInner,
-    pub limits: ResourcesInner,
-}
-impl Default for Resources {
-    fn default() -> Self {
-        Resources {
-            requests: ResourcesInner {
-                cpu: "2".to_string(),
-                memory: "4Gi".to_string(),
-            },
-            limits: ResourcesInner {
-                cpu: "2".to_string(),
-                memory: "4Gi".to_string(),
-            },
-        }
+/// Named sizes, so that most nodes do not have to spell out quantities.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum ResourcePreset {
+    Small,
+    Medium,
+    Large,
+}
+impl ResourcePreset {
+    pub fn resources(&self) -> (ResourcesInner, ResourcesInner) {
+        let (requests, limits) = match self {
+            ResourcePreset::Small => (("100m", "128Mi"), ("250m", "256Mi")),
+            ResourcePreset::Medium => (("500m", "1Gi"), ("1", "2Gi")),
+            ResourcePreset::Large => (("2", "4Gi"), ("2", "4Gi")),
+        };
+        let inner = |(cpu, memory): (&str, &str)| ResourcesInner {
+            cpu: Some(cpu.to_string()),
+            memory: Some(memory.to_string()),
+        };
+        (inner(requests), inner(limits))
     }
 }
-impl From<Resources> for ResourceRequirements {
-    fn from(value: Resources) -> Self {
-        ResourceRequirements {
-            requests: Some((&value.requests).into()),
-            limits: Some((&value.limits).into()),
-            ..Default::default()
+
+/// Requests and limits of one container. Explicit quantities override the
+/// ones of the preset.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+pub struct Resources {
+    pub preset: Option<ResourcePreset>,
+    pub requests: Option<ResourcesInner>,
+    pub limits: Option<ResourcesInner>,
+}
+impl Resources {
+    /// Resolves the preset (falling back to `default_preset`) and checks that
+    /// every quantity parses and no request exceeds its limit. A preset value
+    /// never conflicts with an explicit one: a request above the preset limit
+    /// raises the limit, a limit below the preset request lowers the request.
+    pub fn requirements(
+        &self,
+        default_preset: ResourcePreset,
+    ) -> anyhow::Result<ResourceRequirements> {
+        let (preset_requests, preset_limits) =
+            self.preset.clone().unwrap_or(default_preset).resources();
+        let explicit_requests = self.requests.clone().unwrap_or_default();
+        let explicit_limits = self.limits.clone().unwrap_or_default();
+        let mut requests = explicit_requests.or(preset_requests);
+        let mut limits = explicit_limits.or(preset_limits);
+
+        for (name, request, limit, explicit_request, explicit_limit) in [
+            (
+                "cpu",
+                &mut requests.cpu,
+                &mut limits.cpu,
+                explicit_requests.cpu.is_some(),
+                explicit_limits.cpu.is_some(),
+            ),
+            (
+                "memory",
+                &mut requests.memory,
+                &mut limits.memory,
+                explicit_requests.memory.is_some(),
+                explicit_limits.memory.is_some(),
+            ),
+        ] {
+            let request_value = request.as_deref().map(parse_quantity).transpose()?;
+            let limit_value = limit.as_deref().map(parse_quantity).transpose()?;
+            if let (Some(request_value), Some(limit_value)) = (request_value, limit_value) {
+                if request_value > limit_value {
+                    match (explicit_request, explicit_limit) {
+                        (true, false) => *limit = request.clone(),
+                        (false, true) => *request = limit.clone(),
+                        _ => bail!("{} request exceeds its limit", name),
+                    }
+                }
+            }
         }
+
+        Ok(ResourceRequirements {
+            requests: Some((&requests).into()),
+            limits: Some((&limits).into()),
+            ..Default::default()
+        })
     }
 }
 
+/// Resources for the containers next to `main`, which is configured through
+/// `spec.resources`.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerResources {
+    pub sidecar: Option<Resources>,
+    pub open_head: Option<Resources>,
+    pub dmtrctl: Option<Resources>,
+}
+
 #[derive(CustomResource, Deserialize, Serialize, Clone, Debug, JsonSchema)]
 #[kube(
     kind = "HydraDoomNode",
@@ -93,6 +273,152 @@ pub struct HydraDoomNodeSpec {
     pub start_chain_from: Option<String>,
     pub asleep: Option<bool>,
     pub resources: Option<Resources>,
+    pub container_resources: Option<ContainerResources>,
+    pub idle_policy: Option<IdlePolicy>,
+    pub peer_exposure: Option<PeerExposure>,
+    /// Nodes sharing the same head id are allowed to reach each other's peer
+    /// port when network policies are enabled.
+    pub head: Option<String>,
+    /// Existing secret with `hydra.sk` and `hydra.vk` to use instead of a key
+    /// pair generated by the operator. Label it `hydra.doom/roll-nodes: "true"`
+    /// to restart the node when it changes.
+    pub hydra_key_secret: Option<String>,
+    pub cardano_keys: Option<CardanoKeyMode>,
+    pub security_context: Option<NodeSecurityContext>,
+    pub scheduling: Option<Scheduling>,
+    pub probes: Option<NodeProbes>,
+    /// Changes to the generated Deployment, Service, Ingress and ConfigMap,
+    /// applied after everything else.
+    pub overrides: Option<Overrides>,
+}
+
+/// Probe overrides. A probe given here replaces the generated default as a
+/// whole.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeProbes {
+    pub main_startup: Option<Probe>,
+    pub main_liveness: Option<Probe>,
+    pub main_readiness: Option<Probe>,
+    pub sidecar_liveness: Option<Probe>,
+    pub sidecar_readiness: Option<Probe>,
+}
+
+/// Where the node's pod may run. Everything except `profile` is passed
+/// through to the PodSpec unchanged.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Scheduling {
+    pub profile: Option<SchedulingProfile>,
+    pub node_selector: Option<BTreeMap<String, String>>,
+    pub tolerations: Option<Vec<Toleration>>,
+    pub affinity: Option<Affinity>,
+    pub topology_spread_constraints: Option<Vec<TopologySpreadConstraint>>,
+    pub priority_class_name: Option<String>,
+    pub runtime_class_name: Option<String>,
+}
+
+/// Opt-in placement profile. Without one the pod runs on regular cluster
+/// nodes.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum SchedulingProfile {
+    /// Labels the pod with `run-on: fargate`, which is what our Fargate
+    /// profiles select on.
+    Fargate,
+}
+
+/// Security settings applied to the node's pod and all of its containers.
+/// Anything left out keeps the defaults, which satisfy the Pod Security
+/// Admission "restricted" profile.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeSecurityContext {
+    pub run_as_user: Option<i64>,
+    pub run_as_group: Option<i64>,
+    pub fs_group: Option<i64>,
+    pub run_as_non_root: Option<bool>,
+    pub read_only_root_filesystem: Option<bool>,
+    pub allow_privilege_escalation: Option<bool>,
+    /// Capabilities added back after dropping `ALL`.
+    pub add_capabilities: Option<Vec<String>>,
+    /// `RuntimeDefault`, `Unconfined` or `Localhost`.
+    pub seccomp_profile: Option<String>,
+    /// Profile path, only used with `Localhost`.
+    pub seccomp_localhost_profile: Option<String>,
+}
+impl NodeSecurityContext {
+    pub fn pod(&self) -> PodSecurityContext {
+        PodSecurityContext {
+            run_as_non_root: Some(self.run_as_non_root.unwrap_or(true)),
+            run_as_user: Some(self.run_as_user.unwrap_or(1000)),
+            run_as_group: Some(self.run_as_group.unwrap_or(1000)),
+            fs_group: Some(self.fs_group.unwrap_or(1000)),
+            seccomp_profile: Some(SeccompProfile {
+                type_: self
+                    .seccomp_profile
+                    .clone()
+                    .unwrap_or("RuntimeDefault".to_string()),
+                localhost_profile: self.seccomp_localhost_profile.clone(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    pub fn container(&self) -> SecurityContext {
+        SecurityContext {
+            run_as_non_root: Some(self.run_as_non_root.unwrap_or(true)),
+            read_only_root_filesystem: Some(self.read_only_root_filesystem.unwrap_or(true)),
+            allow_privilege_escalation: Some(self.allow_privilege_escalation.unwrap_or(false)),
+            capabilities: Some(Capabilities {
+                drop: Some(vec!["ALL".to_string()]),
+                add: self.add_capabilities.clone(),
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+/// Which Cardano key the node signs with.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub enum CardanoKeyMode {
+    /// The admin key shared by every node (`Config::secret`).
+    #[default]
+    Shared,
+    /// A payment key generated for this node only. Its secret is not owned
+    /// by the node and is kept when the node is deleted, as the address may
+    /// still hold funds; a node recreated under the same name reuses it.
+    PerNode,
+}
+
+/// How the hydra peer port is published for other parties of the head.
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub enum PeerExposure {
+    #[default]
+    None,
+    ClusterIP,
+    // Requires the operator's PEER_NODE_ADDRESS, an address of the cluster's
+    // nodes that peers can reach.
+    NodePort,
+    LoadBalancer,
+}
+impl PeerExposure {
+    pub fn service_type(&self) -> Option<String> {
+        match self {
+            PeerExposure::None => None,
+            PeerExposure::ClusterIP => Some("ClusterIP".to_string()),
+            PeerExposure::NodePort => Some("NodePort".to_string()),
+            PeerExposure::LoadBalancer => Some("LoadBalancer".to_string()),
+        }
+    }
+}
+
+/// Opt-in policy that lets the operator put a node to sleep once its
+/// transaction counter has not moved for `idle_seconds`. Only nodes that are
+/// Online are considered; open heads and every other state are exempt.
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct IdlePolicy {
+    pub idle_seconds: u64,
 }
 
 #[derive(Deserialize, Serialize, Clone, Default, Debug, JsonSchema)]
@@ -102,35 +428,369 @@ pub struct HydraDoomNodeStatus {
     pub external_url: String,
     pub state: String,
     pub transactions: i64,
+    pub last_activity_at: Option<String>,
+    pub sleep_reason: Option<String>,
+    pub observed_at: Option<String>,
+    pub opened_at: Option<String>,
+    pub uptime_seconds: Option<u64>,
+    pub total_transactions: Option<i64>,
+    pub counted_transactions: Option<i64>,
+    pub transitions: Option<Vec<StateTransition>>,
+    pub peer_address: Option<String>,
+    pub access_token_secret: Option<String>,
+    pub dns_ready: Option<bool>,
+    pub hydra_verification_key: Option<String>,
+    pub hydra_verification_key_hash: Option<String>,
+    pub cardano_address: Option<String>,
+    /// Set while the pod sits on a cordoned node but cannot be evicted
+    /// because its head needs it: since when, and which node.
+    pub eviction_blocked_at: Option<String>,
+    pub eviction_blocked_node: Option<String>,
+    /// Why the node's deployment cannot be built from its spec, e.g. invalid
+    /// resources. The pod keeps running with the last valid spec meanwhile.
+    pub spec_error: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StateTransition {
+    pub state: String,
+    pub at: String,
 }
 impl HydraDoomNodeStatus {
     pub fn offline(crd: &HydraDoomNode, config: &Config, constants: &K8sConstants) -> Self {
         Self {
             state: "Offline".to_string(),
             transactions: 0,
-            local_url: format!("ws://{}:{}", crd.internal_host(), constants.port),
-            external_url: format!(
-                "ws://{}:{}",
-                crd.external_host(config, constants),
-                config.external_port
-            ),
+            local_url: crd.local_url(constants),
+            external_url: crd.external_url(config, constants),
+            ..Default::default()
         }
     }
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug, Default, PartialEq, JsonSchema)]
+pub enum PoolTargetState {
+    #[default]
+    Online,
+    HeadIsOpen,
+}
+impl From<&PoolTargetState> for String {
+    fn from(value: &PoolTargetState) -> Self {
+        match value {
+            PoolTargetState::Online => "Online".to_string(),
+            PoolTargetState::HeadIsOpen => "HeadIsOpen".to_string(),
+        }
+    }
+}
+
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, JsonSchema)]
+#[kube(
+    kind = "HydraDoomNodePool",
+    group = "hydra.doom",
+    version = "v1alpha1",
+    shortname = "hydradoomnodepool",
+    category = "hydradoom",
+    plural = "hydradoomnodepools",
+    namespaced
+)]
+#[kube(status = "HydraDoomNodePoolStatus")]
+#[kube(printcolumn = r#"
+        {"name": "Replicas", "jsonPath":".spec.replicas", "type": "integer"},
+        {"name": "Ready", "jsonPath":".status.ready", "type": "integer"},
+        {"name": "Warming", "jsonPath":".status.warming", "type": "integer"},
+        {"name": "Claimed", "jsonPath":".status.claimed", "type": "integer"}
+    "#)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomNodePoolSpec {
+    /// Number of unclaimed nodes kept warm.
+    pub replicas: u32,
+    pub target_state: Option<PoolTargetState>,
+    /// Claimed nodes are released (deleted) this long after being bound.
+    pub claim_ttl_seconds: Option<u64>,
+    pub template: HydraDoomNodeSpec,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomNodePoolStatus {
+    pub ready: u32,
+    pub warming: u32,
+    pub claimed: u32,
+}
+
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, JsonSchema)]
+#[kube(
+    kind = "HydraDoomNodeClaim",
+    group = "hydra.doom",
+    version = "v1alpha1",
+    shortname = "hydradoomnodeclaim",
+    category = "hydradoom",
+    plural = "hydradoomnodeclaims",
+    namespaced
+)]
+#[kube(status = "HydraDoomNodeClaimStatus")]
+#[kube(printcolumn = r#"
+        {"name": "Pool", "jsonPath":".spec.pool", "type": "string"},
+        {"name": "Phase", "jsonPath":".status.phase", "type": "string"},
+        {"name": "Node", "jsonPath":".status.node", "type": "string"},
+        {"name": "External URI", "jsonPath": ".status.externalUrl", "type": "string"}
+    "#)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomNodeClaimSpec {
+    pub pool: String,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomNodeClaimStatus {
+    pub phase: String,
+    pub node: Option<String>,
+    pub external_url: Option<String>,
+    pub bound_at: Option<String>,
+}
+
+/// Operator settings that can be changed without redeploying the operator.
+/// Every field given here overrides the corresponding env var and the
+/// `ClusterHydraDoomConfig`; the operator only reads the one named by
+/// `HYDRA_DOOM_CONFIG` in its own namespace.
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[kube(
+    kind = "HydraDoomConfig",
+    group = "hydra.doom",
+    version = "v1alpha1",
+    shortname = "hydradoomconfig",
+    category = "hydradoom",
+    plural = "hydradoomconfigs",
+    namespaced
+)]
+#[kube(status = "HydraDoomConfigStatus")]
+#[kube(printcolumn = r#"
+        {"name": "Valid", "jsonPath":".status.conditions[?(@.type==\"Valid\")].status", "type": "string"}
+    "#)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomConfigSpec {
+    pub image: Option<String>,
+    pub open_head_image: Option<String>,
+    pub sidecar_image: Option<String>,
+    pub configmap: Option<String>,
+    pub secret: Option<String>,
+    pub external_domain: Option<String>,
+    pub external_port: Option<String>,
+    pub admin_addr: Option<String>,
+    pub hydra_scripts_tx_id: Option<String>,
+    pub dmtr_project_id: Option<String>,
+    pub dmtr_port_name: Option<String>,
+    pub blockfrost_key_ref: Option<SecretKeyRef>,
+    pub dmtr_api_key_ref: Option<SecretKeyRef>,
+}
+
+/// Cluster-wide version of `HydraDoomConfig`, shared by operators in several
+/// namespaces. Its settings override the env vars and are in turn overridden
+/// by the namespaced one; only the one named by `HYDRA_DOOM_CONFIG` is read.
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[kube(
+    kind = "ClusterHydraDoomConfig",
+    group = "hydra.doom",
+    version = "v1alpha1",
+    shortname = "clusterhydradoomconfig",
+    category = "hydradoom",
+    plural = "clusterhydradoomconfigs"
+)]
+#[kube(status = "HydraDoomConfigStatus")]
+#[kube(printcolumn = r#"
+        {"name": "Valid", "jsonPath":".status.conditions[?(@.type==\"Valid\")].status", "type": "string"}
+    "#)]
+pub struct ClusterHydraDoomConfigSpec {
+    #[serde(flatten)]
+    pub settings: HydraDoomConfigSpec,
+}
+
+#[derive(Deserialize, Serialize, Clone, Default, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomConfigStatus {
+    pub observed_generation: Option<i64>,
+    pub conditions: Vec<Condition>,
+}
+
+/// Immutable record of a node's run, written when the node is deleted.
+#[derive(CustomResource, Deserialize, Serialize, Clone, Debug, JsonSchema)]
+#[kube(
+    kind = "HydraDoomNodeReport",
+    group = "hydra.doom",
+    version = "v1alpha1",
+    shortname = "hydradoomnodereport",
+    category = "hydradoom",
+    plural = "hydradoomnodereports",
+    namespaced
+)]
+#[kube(printcolumn = r#"
+        {"name": "Node", "jsonPath":".spec.node", "type": "string"},
+        {"name": "Transactions", "jsonPath":".spec.totalTransactions", "type": "integer"},
+        {"name": "Time To Open", "jsonPath":".spec.timeToOpenSeconds", "type": "integer"},
+        {"name": "Uptime", "jsonPath":".spec.uptimeSeconds", "type": "integer"}
+    "#)]
+#[serde(rename_all = "camelCase")]
+pub struct HydraDoomNodeReportSpec {
+    pub node: String,
+    pub created_at: Option<String>,
+    pub deleted_at: Option<String>,
+    pub opened_at: Option<String>,
+    pub time_to_open_seconds: Option<i64>,
+    pub uptime_seconds: u64,
+    pub total_transactions: i64,
+    pub transitions: Vec<StateTransition>,
+    pub final_status: Option<HydraDoomNodeStatus>,
+    pub node_spec: HydraDoomNodeSpec,
+}
+impl HydraDoomNodeReport {
+    /// CRD for reports, with a validation rule rejecting any change to a
+    /// report once it has been written.
+    pub fn immutable_crd() -> CustomResourceDefinition {
+        let mut crd = Self::crd();
+        for version in crd.spec.versions.iter_mut() {
+            if let Some(spec) = version
+                .schema
+                .as_mut()
+                .and_then(|schema| schema.open_api_v3_schema.as_mut())
+                .and_then(|schema| schema.properties.as_mut())
+                .and_then(|properties| properties.get_mut("spec"))
+            {
+                spec.x_kubernetes_validations = Some(vec![ValidationRule {
+                    rule: "self == oldSelf".to_string(),
+                    message: Some("Reports are immutable".to_string()),
+                    ..Default::default()
+                }]);
+            }
+        }
+        crd
+    }
+}
+
 impl HydraDoomNode {
     pub fn internal_name(&self) -> String {
         format!("hydra-doom-node-{}", self.name_any())
     }
 
+    /// Selector labels of the node's pods.
     pub fn internal_labels(&self) -> BTreeMap<String, String> {
         BTreeMap::from([
             ("component".to_string(), "hydra-doom-node".to_string()),
             ("hydra-doom-node-id".to_string(), self.name_any()),
-            ("run-on".to_string(), "fargate".to_string()),
         ])
     }
 
+    /// Resource requirements of one of the pod's containers.
+    pub fn resource_requirements(&self, container: &str) -> anyhow::Result<ResourceRequirements> {
+        let others = self.spec.container_resources.clone().unwrap_or_default();
+        let (resources, default_preset) = match container {
+            "main" => (self.spec.resources.clone(), ResourcePreset::Large),
+            "sidecar" => (others.sidecar, ResourcePreset::Small),
+            "open-head" => (others.open_head, ResourcePreset::Small),
+            "dmtrctl" => (others.dmtrctl, ResourcePreset::Small),
+            _ => bail!("Unknown container {}", container),
+        };
+        resources
+            .unwrap_or_default()
+            .requirements(default_preset)
+            .map_err(|err| anyhow!("Invalid resources for container {}: {}", container, err))
+    }
+
+    pub fn validate_resources(&self) -> anyhow::Result<()> {
+        for container in ["main", "sidecar", "open-head", "dmtrctl"] {
+            self.resource_requirements(container)?;
+        }
+        Ok(())
+    }
+
+    pub fn probes(&self, constants: &K8sConstants) -> NodeProbes {
+        let overrides = self.spec.probes.clone().unwrap_or_default();
+        let api = || Probe {
+            tcp_socket: Some(TCPSocketAction {
+                port: IntOrString::String("api".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let metrics = || Probe {
+            http_get: Some(HTTPGetAction {
+                path: Some(constants.metrics_endpoint.clone()),
+                port: IntOrString::String("metrics".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        NodeProbes {
+            // Until the chain is synced the API port stays closed, which can
+            // take a while, so allow for up to half an hour.
+            main_startup: overrides.main_startup.or(Some(Probe {
+                period_seconds: Some(10),
+                failure_threshold: Some(180),
+                ..api()
+            })),
+            main_liveness: overrides.main_liveness.or(Some(Probe {
+                period_seconds: Some(10),
+                failure_threshold: Some(6),
+                ..api()
+            })),
+            main_readiness: overrides.main_readiness.or(Some(Probe {
+                period_seconds: Some(5),
+                failure_threshold: Some(3),
+                ..api()
+            })),
+            sidecar_liveness: overrides.sidecar_liveness.or(Some(Probe {
+                period_seconds: Some(10),
+                failure_threshold: Some(6),
+                ..metrics()
+            })),
+            sidecar_readiness: overrides.sidecar_readiness.or(Some(Probe {
+                period_seconds: Some(5),
+                failure_threshold: Some(3),
+                ..metrics()
+            })),
+        }
+    }
+
+    pub fn scheduling(&self) -> Scheduling {
+        self.spec.scheduling.clone().unwrap_or_default()
+    }
+
+    /// Secrets the node's pod mounts or reads env vars from, other than the
+    /// key secrets the operator generates, which never change.
+    pub fn referenced_secrets(&self, config: &Config) -> Vec<String> {
+        let mut secrets = vec![];
+        if let Some(secret) = &self.spec.hydra_key_secret {
+            secrets.push(secret.clone());
+        }
+        if !self.per_node_cardano_keys() {
+            secrets.push(config.secret.clone());
+        }
+        if !self.spec.offline.unwrap_or(false) {
+            secrets.push(config.blockfrost_key_ref.name.clone());
+            secrets.push(config.dmtr_api_key_ref.name.clone());
+        }
+        secrets.dedup();
+        secrets
+    }
+
+    /// ConfigMaps the node's pod mounts.
+    pub fn referenced_configmaps(&self, config: &Config) -> Vec<String> {
+        vec![config.configmap.clone(), self.internal_name()]
+    }
+
+    /// Labels set on the pods, on top of the selector labels.
+    pub fn pod_labels(&self) -> BTreeMap<String, String> {
+        let mut labels = self.internal_labels();
+        if let Some(head) = &self.spec.head {
+            labels.insert(HEAD_LABEL.to_string(), head.clone());
+        }
+        if self.scheduling().profile == Some(SchedulingProfile::Fargate) {
+            labels.insert("run-on".to_string(), "fargate".to_string());
+        }
+        labels
+    }
+
     pub fn internal_host(&self) -> String {
         format!(
             "{}.{}.svc.cluster.local",
@@ -139,8 +799,157 @@ impl HydraDoomNode {
         )
     }
 
+    fn render_template(&self, template: &str, config: &Config) -> String {
+        template
+            .replace("{name}", &self.name_any())
+            .replace("{namespace}", &self.namespace().unwrap())
+            .replace("{domain}", &config.external_domain)
+    }
+
     pub fn external_host(&self, config: &Config, _constants: &K8sConstants) -> String {
-        format!("{}.{}", self.name_any(), config.external_domain)
+        self.render_template(&config.external_host_template, config)
+    }
+
+    /// Path prefix the node is served under when path-based routing is used.
+    pub fn external_path(&self, config: &Config) -> Option<String> {
+        config
+            .external_path_template
+            .as_ref()
+            .map(|template| self.render_template(template, config))
+    }
+
+    /// Whether a request for `host` and `path` is routed to this node.
+    pub fn serves(
+        &self,
+        host: &str,
+        path: &str,
+        config: &Config,
+        constants: &K8sConstants,
+    ) -> bool {
+        self.external_host(config, constants) == host
+            && match self.external_path(config) {
+                Some(prefix) => {
+                    path == prefix
+                        || path.starts_with(&format!("{}/", prefix))
+                        || path.starts_with(&format!("{}?", prefix))
+                }
+                None => true,
+            }
+    }
+
+    pub fn local_url(&self, constants: &K8sConstants) -> String {
+        format!("ws://{}:{}", self.internal_host(), constants.port)
+    }
+
+    pub fn external_url(&self, config: &Config, constants: &K8sConstants) -> String {
+        let (scheme, port) = match config.tls_mode {
+            TlsMode::None => ("ws", &config.external_port),
+            _ => ("wss", &config.tls_external_port),
+        };
+        format!(
+            "{}://{}:{}{}",
+            scheme,
+            self.external_host(config, constants),
+            port,
+            self.external_path(config).unwrap_or_default()
+        )
+    }
+
+    /// Service name and port the external traffic for this node should reach.
+    /// Sleeping nodes are routed to the activator (when one is configured) so
+    /// that the first connection wakes them up instead of getting a 503.
+    pub fn external_backend(&self, config: &Config, constants: &K8sConstants) -> (String, i32) {
+        match &config.activator_service {
+            Some(activator) if self.routes_to_activator(config) => {
+                (activator.clone(), config.activator_port)
+            }
+            _ => (self.internal_name(), constants.port),
+        }
+    }
+
+    pub fn routes_to_activator(&self, config: &Config) -> bool {
+        config.activator_service.is_some() && self.spec.asleep.unwrap_or(false)
+    }
+
+    /// external-dns annotations for the object exposing the node.
+    pub fn external_dns_annotations(
+        &self,
+        config: &Config,
+        constants: &K8sConstants,
+    ) -> BTreeMap<String, String> {
+        let mut annotations = BTreeMap::new();
+        if !config.external_dns {
+            return annotations;
+        }
+
+        annotations.insert(
+            "external-dns.alpha.kubernetes.io/hostname".to_string(),
+            self.external_host(config, constants),
+        );
+        if let Some(ttl) = config.external_dns_ttl {
+            annotations.insert(
+                "external-dns.alpha.kubernetes.io/ttl".to_string(),
+                ttl.to_string(),
+            );
+        }
+        if let Some(target) = &config.external_dns_target {
+            annotations.insert(
+                "external-dns.alpha.kubernetes.io/target".to_string(),
+                target.clone(),
+            );
+        }
+        annotations
+    }
+
+    /// Whether the path prefix has to be stripped before reaching the node.
+    /// The activator is handed the full path, as it needs the prefix to know
+    /// which node is being woken up, and strips it itself.
+    fn rewrites_path(&self, config: &Config) -> bool {
+        config.external_path_template.is_some() && !self.routes_to_activator(config)
+    }
+
+    pub fn report(&self) -> HydraDoomNodeReport {
+        let status = self.status.clone();
+        let created_at = self.metadata.creation_timestamp.as_ref().map(|at| at.0);
+        let deleted_at = self.metadata.deletion_timestamp.as_ref().map(|at| at.0);
+        let opened_at = status
+            .as_ref()
+            .and_then(|status| status.opened_at.as_deref())
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+            .map(|at| at.with_timezone(&Utc));
+
+        let mut report = HydraDoomNodeReport::new(
+            &format!(
+                "{}-{}",
+                self.name_any(),
+                deleted_at.unwrap_or_else(Utc::now).timestamp()
+            ),
+            HydraDoomNodeReportSpec {
+                node: self.name_any(),
+                created_at: created_at.map(|at| at.to_rfc3339()),
+                deleted_at: deleted_at.map(|at| at.to_rfc3339()),
+                opened_at: opened_at.map(|at| at.to_rfc3339()),
+                time_to_open_seconds: created_at
+                    .zip(opened_at)
+                    .map(|(created_at, opened_at)| (opened_at - created_at).num_seconds()),
+                uptime_seconds: status
+                    .as_ref()
+                    .and_then(|status| status.uptime_seconds)
+                    .unwrap_or(0),
+                total_transactions: status
+                    .as_ref()
+                    .and_then(|status| status.total_transactions)
+                    .unwrap_or(0),
+                transitions: status
+                    .as_ref()
+                    .and_then(|status| status.transitions.clone())
+                    .unwrap_or_default(),
+                final_status: status,
+                node_spec: self.spec.clone(),
+            },
+        );
+        report.metadata.namespace = self.namespace();
+        report
     }
 
     pub fn configmap(&self, config: &Config, _constants: &K8sConstants) -> ConfigMap {
@@ -169,7 +978,11 @@ impl HydraDoomNode {
         }
     }
 
-    pub fn deployment(&self, config: &Config, constants: &K8sConstants) -> Deployment {
+    pub fn deployment(
+        &self,
+        config: &Config,
+        constants: &K8sConstants,
+    ) -> anyhow::Result<Deployment> {
         let name = self.internal_name();
         let labels = self.internal_labels();
 
@@ -180,11 +993,11 @@ impl HydraDoomNode {
             "--api-host".to_string(),
             "0.0.0.0".to_string(),
             "--port".to_string(),
-            "5001".to_string(),
+            constants.node_port.to_string(),
             "--api-port".to_string(),
             constants.port.to_string(),
             "--hydra-signing-key".to_string(),
-            format!("{}/hydra.sk", constants.data_dir),
+            format!("{}/{}", constants.hydra_key_dir, HYDRA_SIGNING_KEY),
             "--ledger-protocol-parameters".to_string(),
             format!("{}/protocol-parameters.json", constants.config_dir),
             "--persistence-dir".to_string(),
@@ -204,7 +1017,7 @@ impl HydraDoomNode {
                 "--node-id".to_string(),
                 self.name_any(),
                 "--cardano-signing-key".to_string(),
-                format!("{}/admin.sk", constants.secret_dir),
+                self.cardano_signing_key_path(constants),
                 "--hydra-scripts-tx-id".to_string(),
                 config.hydra_scripts_tx_id.clone(),
                 "--testnet-magic".to_string(),
@@ -220,17 +1033,26 @@ impl HydraDoomNode {
             aux
         };
 
+        let probes = self.probes(constants);
         let mut containers = vec![
             Container {
                 name: "main".to_string(),
                 image: Some(config.image.clone()),
                 args: Some(main_container_args),
-                ports: Some(vec![ContainerPort {
-                    name: Some("api".to_string()),
-                    container_port: constants.port,
-                    protocol: Some("TCP".to_string()),
-                    ..Default::default()
-                }]),
+                ports: Some(vec![
+                    ContainerPort {
+                        name: Some("api".to_string()),
+                        container_port: constants.port,
+                        protocol: Some("TCP".to_string()),
+                        ..Default::default()
+                    },
+                    ContainerPort {
+                        name: Some("peer".to_string()),
+                        container_port: constants.node_port,
+                        protocol: Some("TCP".to_string()),
+                        ..Default::default()
+                    },
+                ]),
                 volume_mounts: Some(vec![
                     VolumeMount {
                         name: "initialutxo".to_string(),
@@ -247,6 +1069,12 @@ impl HydraDoomNode {
                         mount_path: constants.data_dir.clone(),
                         ..Default::default()
                     },
+                    VolumeMount {
+                        name: "hydra-keys".to_string(),
+                        mount_path: constants.hydra_key_dir.clone(),
+                        read_only: Some(true),
+                        ..Default::default()
+                    },
                     VolumeMount {
                         name: "secret".to_string(),
                         mount_path: constants.secret_dir.clone(),
@@ -258,13 +1086,10 @@ impl HydraDoomNode {
                         ..Default::default()
                     },
                 ]),
-                resources: Some(
-                    self.spec
-                        .resources
-                        .clone()
-                        .unwrap_or(Default::default())
-                        .into(),
-                ),
+                resources: Some(self.resource_requirements("main")?),
+                startup_probe: probes.main_startup,
+                liveness_probe: probes.main_liveness,
+                readiness_probe: probes.main_readiness,
                 ..Default::default()
             },
             Container {
@@ -283,6 +1108,9 @@ impl HydraDoomNode {
                     protocol: Some("TCP".to_string()),
                     ..Default::default()
                 }]),
+                resources: Some(self.resource_requirements("sidecar")?),
+                liveness_probe: probes.sidecar_liveness,
+                readiness_probe: probes.sidecar_readiness,
                 ..Default::default()
             },
         ];
@@ -296,23 +1124,39 @@ impl HydraDoomNode {
                 "--seed-input".to_string(),
                 self.spec.seed_input.clone(),
                 "--participant".to_string(),
-                config.admin_addr.clone(),
+                if self.per_node_cardano_keys() {
+                    "$(PARTICIPANT)".to_string()
+                } else {
+                    config.admin_addr.clone()
+                },
                 "--party-verification-file".to_string(),
-                format!("{}/hydra.vk", constants.data_dir),
+                format!("{}/{}", constants.hydra_key_dir, HYDRA_VERIFICATION_KEY),
                 "--cardano-key-file".to_string(),
-                format!("{}/admin.sk", constants.secret_dir),
+                self.cardano_signing_key_path(constants),
                 "--blockfrost-key".to_string(),
-                config.blockfrost_key.clone(),
+                "$(BLOCKFROST_KEY)".to_string(),
             ];
             if !self.spec.commit_inputs.is_empty() {
                 open_head_args.push("--commit-inputs".to_string());
                 open_head_args.extend(self.spec.commit_inputs.clone());
             }
 
+            let mut open_head_env = vec![secret_env("BLOCKFROST_KEY", &config.blockfrost_key_ref)];
+            if self.per_node_cardano_keys() {
+                open_head_env.push(secret_env(
+                    "PARTICIPANT",
+                    &SecretKeyRef {
+                        name: self.cardano_keys_secret_name(config),
+                        key: PAYMENT_ADDRESS.to_string(),
+                    },
+                ));
+            }
+
             containers.push(Container {
                 name: "open-head".to_string(),
                 image: Some(config.open_head_image.clone()),
                 args: Some(open_head_args),
+                env: Some(open_head_env),
                 volume_mounts: Some(vec![
                     VolumeMount {
                         name: "config".to_string(),
@@ -329,8 +1173,14 @@ impl HydraDoomNode {
                         mount_path: constants.data_dir.clone(),
                         ..Default::default()
                     },
+                    VolumeMount {
+                        name: "hydra-keys".to_string(),
+                        mount_path: constants.hydra_key_dir.clone(),
+                        read_only: Some(true),
+                        ..Default::default()
+                    },
                 ]),
-                resources: None,
+                resources: Some(self.resource_requirements("open-head")?),
                 ..Default::default()
             });
 
@@ -341,23 +1191,41 @@ impl HydraDoomNode {
                     "--project-id".to_string(),
                     config.dmtr_project_id.clone(),
                     "--api-key".to_string(),
-                    config.dmtr_api_key.clone(),
+                    "$(DMTR_API_KEY)".to_string(),
                     "ports".to_string(),
                     "tunnel".to_string(),
                     config.dmtr_port_name.clone(),
                     "--socket".to_string(),
                     constants.socket_path.clone(),
                 ]),
+                env: Some(vec![secret_env("DMTR_API_KEY", &config.dmtr_api_key_ref)]),
                 volume_mounts: Some(vec![VolumeMount {
                     name: "ipc".to_string(),
                     mount_path: constants.socket_dir.clone(),
                     ..Default::default()
                 }]),
+                resources: Some(self.resource_requirements("dmtrctl")?),
                 ..Default::default()
             })
         }
 
-        Deployment {
+        // With a read-only root filesystem every container gets a scratch
+        // /tmp; everything else that is written to lives on explicit volumes.
+        let security_context = self.spec.security_context.clone().unwrap_or_default();
+        let scheduling = self.scheduling();
+        for container in containers.iter_mut() {
+            container.security_context = Some(security_context.container());
+            container
+                .volume_mounts
+                .get_or_insert_with(Vec::new)
+                .push(VolumeMount {
+                    name: "tmp".to_string(),
+                    mount_path: constants.tmp_dir.clone(),
+                    ..Default::default()
+                });
+        }
+
+        Ok(Deployment {
             metadata: ObjectMeta {
                 name: Some(name.clone()),
                 ..Default::default()
@@ -374,36 +1242,36 @@ impl HydraDoomNode {
                 },
                 template: PodTemplateSpec {
                     metadata: Some(ObjectMeta {
-                        labels: Some(labels.clone()),
+                        labels: Some(self.pod_labels()),
                         ..Default::default()
                     }),
                     spec: Some(PodSpec {
-                        init_containers: Some(vec![Container {
-                            name: "init".to_string(),
-                            image: Some(config.image.clone()),
-                            args: Some(vec![
-                                "gen-hydra-key".to_string(),
-                                "--output-file".to_string(),
-                                format!("{}/hydra", constants.data_dir),
-                            ]),
-                            volume_mounts: Some(vec![VolumeMount {
-                                name: "data".to_string(),
-                                mount_path: constants.data_dir.clone(),
-                                ..Default::default()
-                            }]),
-                            ..Default::default()
-                        }]),
                         containers,
+                        security_context: Some(security_context.pod()),
+                        node_selector: scheduling.node_selector,
+                        tolerations: scheduling.tolerations,
+                        affinity: scheduling.affinity,
+                        topology_spread_constraints: scheduling.topology_spread_constraints,
+                        priority_class_name: scheduling.priority_class_name,
+                        runtime_class_name: scheduling.runtime_class_name,
                         volumes: Some(vec![
                             Volume {
                                 name: "data".to_string(),
                                 empty_dir: Some(EmptyDirVolumeSource::default()),
                                 ..Default::default()
                             },
+                            Volume {
+                                name: "hydra-keys".to_string(),
+                                secret: Some(SecretVolumeSource {
+                                    secret_name: Some(self.hydra_keys_secret_name()),
+                                    ..Default::default()
+                                }),
+                                ..Default::default()
+                            },
                             Volume {
                                 name: "secret".to_string(),
                                 secret: Some(SecretVolumeSource {
-                                    secret_name: Some(config.secret.clone()),
+                                    secret_name: Some(self.cardano_keys_secret_name(config)),
                                     ..Default::default()
                                 }),
                                 ..Default::default()
@@ -429,6 +1297,11 @@ impl HydraDoomNode {
                                 empty_dir: Some(EmptyDirVolumeSource::default()),
                                 ..Default::default()
                             },
+                            Volume {
+                                name: "tmp".to_string(),
+                                empty_dir: Some(EmptyDirVolumeSource::default()),
+                                ..Default::default()
+                            },
                         ]),
                         ..Default::default()
                     }),
@@ -436,7 +1309,7 @@ impl HydraDoomNode {
                 ..Default::default()
             }),
             ..Default::default()
-        }
+        })
     }
 
     pub fn service(&self, _config: &Config, constants: &K8sConstants) -> Service {
@@ -480,27 +1353,402 @@ impl HydraDoomNode {
         }
     }
 
+    pub fn hydra_keys_secret_name(&self) -> String {
+        self.spec
+            .hydra_key_secret
+            .clone()
+            .unwrap_or(format!("{}-hydra-keys", self.internal_name()))
+    }
+
+    pub fn hydra_keys_secret(&self, keys: &KeyPair) -> Secret {
+        Secret {
+            metadata: ObjectMeta {
+                name: Some(self.hydra_keys_secret_name()),
+                owner_references: self.controller_owner_ref(&()).map(|owner| vec![owner]),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([
+                (
+                    HYDRA_SIGNING_KEY.to_string(),
+                    ByteString(keys.signing_key.clone().into_bytes()),
+                ),
+                (
+                    HYDRA_VERIFICATION_KEY.to_string(),
+                    ByteString(keys.verification_key.clone().into_bytes()),
+                ),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    pub fn per_node_cardano_keys(&self) -> bool {
+        self.spec.cardano_keys.clone().unwrap_or_default() == CardanoKeyMode::PerNode
+    }
+
+    pub fn cardano_keys_secret_name(&self, config: &Config) -> String {
+        if self.per_node_cardano_keys() {
+            format!("{}-cardano-keys", self.internal_name())
+        } else {
+            config.secret.clone()
+        }
+    }
+
+    /// Path of the Cardano signing key inside the mounted key secret.
+    pub fn cardano_signing_key_path(&self, constants: &K8sConstants) -> String {
+        let file = if self.per_node_cardano_keys() {
+            PAYMENT_SIGNING_KEY
+        } else {
+            "admin.sk"
+        };
+        format!("{}/{}", constants.secret_dir, file)
+    }
+
+    /// Secret holding the node's own Cardano keys. Deliberately without an
+    /// owner reference: deleting the node must not garbage collect a key
+    /// that may control funds. Leftovers carry `CARDANO_KEYS_LABEL` and are
+    /// removed by hand once drained.
+    pub fn cardano_keys_secret(&self, keys: &KeyPair, address: &str, config: &Config) -> Secret {
+        Secret {
+            metadata: ObjectMeta {
+                name: Some(self.cardano_keys_secret_name(config)),
+                labels: Some(BTreeMap::from([(
+                    CARDANO_KEYS_LABEL.to_string(),
+                    self.name_any(),
+                )])),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([
+                (
+                    PAYMENT_SIGNING_KEY.to_string(),
+                    ByteString(keys.signing_key.clone().into_bytes()),
+                ),
+                (
+                    PAYMENT_VERIFICATION_KEY.to_string(),
+                    ByteString(keys.verification_key.clone().into_bytes()),
+                ),
+                (
+                    PAYMENT_ADDRESS.to_string(),
+                    ByteString(address.as_bytes().to_vec()),
+                ),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    /// Operator-managed secret holding the credentials passed as raw values.
+    /// Shared by every node in the namespace, so it has no owner.
+    pub fn credentials_secret(&self, config: &Config) -> Option<Secret> {
+        let credentials = config.managed_credentials();
+        if credentials.is_empty() {
+            return None;
+        }
+
+        Some(Secret {
+            metadata: ObjectMeta {
+                name: Some(config.credentials_secret.clone()),
+                labels: Some(BTreeMap::from([(
+                    ROLL_LABEL.to_string(),
+                    "true".to_string(),
+                )])),
+                ..Default::default()
+            },
+            data: Some(
+                credentials
+                    .into_iter()
+                    .map(|(key, value)| (key, ByteString(value.into_bytes())))
+                    .collect(),
+            ),
+            ..Default::default()
+        })
+    }
+
+    pub fn token_secret_name(&self) -> String {
+        format!("{}-token", self.internal_name())
+    }
+
+    pub fn token_secret(&self, token: &str, rotated_for: &str) -> Secret {
+        Secret {
+            metadata: ObjectMeta {
+                name: Some(self.token_secret_name()),
+                annotations: Some(BTreeMap::from([(
+                    ROTATED_FOR_ANNOTATION.to_string(),
+                    rotated_for.to_string(),
+                )])),
+                owner_references: self.controller_owner_ref(&()).map(|owner| vec![owner]),
+                ..Default::default()
+            },
+            data: Some(BTreeMap::from([(
+                TOKEN_KEY.to_string(),
+                ByteString(token.as_bytes().to_vec()),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    pub fn peer_service_name(&self) -> String {
+        format!("{}-peer", self.internal_name())
+    }
+
+    pub fn peer_service(&self, _config: &Config, constants: &K8sConstants) -> Option<Service> {
+        let type_ = self
+            .spec
+            .peer_exposure
+            .clone()
+            .unwrap_or_default()
+            .service_type()?;
+
+        Some(Service {
+            metadata: ObjectMeta {
+                name: Some(self.peer_service_name()),
+                ..Default::default()
+            },
+            spec: Some(ServiceSpec {
+                selector: Some(self.internal_labels()),
+                ports: Some(vec![ServicePort {
+                    name: Some("peer".to_string()),
+                    port: constants.node_port,
+                    target_port: Some(
+                        k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(
+                            constants.node_port,
+                        ),
+                    ),
+                    protocol: Some("TCP".to_string()),
+                    ..Default::default()
+                }]),
+                type_: Some(type_),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    /// Whether voluntary disruptions (drains, cluster upgrades) have to wait,
+    /// because the head lives in the pod's ephemeral state.
+    pub fn disruption_protected(&self) -> bool {
+        self.status
+            .as_ref()
+            .is_some_and(|status| self.disruption_protected_in(&status.state))
+    }
+
+    pub fn disruption_protected_in(&self, state: &str) -> bool {
+        !self.spec.asleep.unwrap_or(false)
+            && (state == String::from(HydraDoomNodeState::HeadIsOpen)
+                || state == String::from(HydraDoomNodeState::HeadIsInitializing))
+    }
+
+    pub fn pod_disruption_budget(&self) -> Option<PodDisruptionBudget> {
+        if !self.disruption_protected() {
+            return None;
+        }
+
+        Some(PodDisruptionBudget {
+            metadata: ObjectMeta {
+                name: Some(self.internal_name()),
+                ..Default::default()
+            },
+            spec: Some(PodDisruptionBudgetSpec {
+                min_available: Some(IntOrString::Int(1)),
+                selector: Some(LabelSelector {
+                    match_labels: Some(self.internal_labels()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })
+    }
+
+    pub fn network_policy(&self, config: &Config, constants: &K8sConstants) -> NetworkPolicy {
+        let namespace_peer = |namespace: &str| NetworkPolicyPeer {
+            namespace_selector: Some(LabelSelector {
+                match_labels: Some(BTreeMap::from([(
+                    "kubernetes.io/metadata.name".to_string(),
+                    namespace.to_string(),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let tcp_port = |port: i32| NetworkPolicyPort {
+            port: Some(IntOrString::Int(port)),
+            protocol: Some("TCP".to_string()),
+            ..Default::default()
+        };
+        let head_peer = self.spec.head.as_ref().map(|head| NetworkPolicyPeer {
+            pod_selector: Some(LabelSelector {
+                match_labels: Some(BTreeMap::from([(HEAD_LABEL.to_string(), head.clone())])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let cidr_peers: Vec<_> = config
+            .egress_cidrs
+            .iter()
+            .map(|cidr| NetworkPolicyPeer {
+                ip_block: Some(IPBlock {
+                    cidr: cidr.clone(),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect();
+
+        let mut metrics_from = vec![namespace_peer(&config.operator_namespace)];
+        if let Some(prometheus_namespace) = &config.prometheus_namespace {
+            metrics_from.push(namespace_peer(prometheus_namespace));
+        }
+
+        // Peers outside the cluster can only reach an externally exposed port.
+        let peer_from = match self.spec.peer_exposure.clone().unwrap_or_default() {
+            PeerExposure::NodePort | PeerExposure::LoadBalancer => Some(vec![]),
+            _ => head_peer.clone().map(|peer| vec![peer]),
+        };
+
+        let mut ingress = vec![
+            NetworkPolicyIngressRule {
+                // The activator in the operator namespace proxies to the API.
+                from: Some(vec![
+                    namespace_peer(&config.ingress_namespace),
+                    namespace_peer(&config.operator_namespace),
+                ]),
+                ports: Some(vec![tcp_port(constants.port)]),
+            },
+            NetworkPolicyIngressRule {
+                from: Some(metrics_from),
+                ports: Some(vec![tcp_port(constants.metrics_port)]),
+            },
+        ];
+        if let Some(peer_from) = peer_from {
+            ingress.push(NetworkPolicyIngressRule {
+                from: (!peer_from.is_empty()).then_some(peer_from),
+                ports: Some(vec![tcp_port(constants.node_port)]),
+            });
+        }
+
+        let mut egress = vec![NetworkPolicyEgressRule {
+            to: Some(vec![namespace_peer("kube-system")]),
+            ports: Some(vec![
+                NetworkPolicyPort {
+                    port: Some(IntOrString::Int(53)),
+                    protocol: Some("UDP".to_string()),
+                    ..Default::default()
+                },
+                tcp_port(53),
+            ]),
+        }];
+        // An empty `to` would allow every destination.
+        if !cidr_peers.is_empty() {
+            egress.push(NetworkPolicyEgressRule {
+                to: Some(cidr_peers),
+                ports: Some(
+                    config
+                        .egress_ports
+                        .iter()
+                        .chain([constants.node_port].iter())
+                        .map(|port| tcp_port(*port))
+                        .collect(),
+                ),
+            });
+        }
+        if let Some(head_peer) = head_peer {
+            egress.push(NetworkPolicyEgressRule {
+                to: Some(vec![head_peer]),
+                ports: Some(vec![tcp_port(constants.node_port)]),
+            });
+        }
+
+        NetworkPolicy {
+            metadata: ObjectMeta {
+                name: Some(self.internal_name()),
+                ..Default::default()
+            },
+            spec: Some(NetworkPolicySpec {
+                pod_selector: LabelSelector {
+                    match_labels: Some(self.internal_labels()),
+                    ..Default::default()
+                },
+                policy_types: Some(vec!["Ingress".to_string(), "Egress".to_string()]),
+                ingress: Some(ingress),
+                egress: Some(egress),
+            }),
+        }
+    }
+
     pub fn ingress(&self, config: &Config, constants: &K8sConstants) -> Ingress {
         let name = self.internal_name();
+        let (backend_name, backend_port) = self.external_backend(config, constants);
+        let host = self.external_host(config, constants);
+
+        let mut annotations = config.ingress_annotations(constants);
+        annotations.extend(self.external_dns_annotations(config, constants));
+        let tls = match config.tls_mode {
+            TlsMode::Wildcard => Some(vec![IngressTLS {
+                hosts: Some(vec![host.clone()]),
+                secret_name: config.tls_secret.clone(),
+            }]),
+            TlsMode::CertManager => {
+                let issuer_annotation = if config.cert_manager_issuer_kind == "Issuer" {
+                    "cert-manager.io/issuer"
+                } else {
+                    "cert-manager.io/cluster-issuer"
+                };
+                if let Some(issuer) = &config.cert_manager_issuer {
+                    annotations.insert(issuer_annotation.to_string(), issuer.clone());
+                }
+                Some(vec![IngressTLS {
+                    hosts: Some(vec![host.clone()]),
+                    secret_name: Some(format!("{}-tls", name)),
+                }])
+            }
+            TlsMode::None | TlsMode::Upstream => None,
+        };
+
+        let (path, path_type) = match self.external_path(config) {
+            Some(prefix) if self.rewrites_path(config) => {
+                annotations.insert(
+                    "nginx.ingress.kubernetes.io/use-regex".to_string(),
+                    "true".to_string(),
+                );
+                annotations.insert(
+                    "nginx.ingress.kubernetes.io/rewrite-target".to_string(),
+                    "/$2".to_string(),
+                );
+                (
+                    format!("{}(/|$)(.*)", regex::escape(&prefix)),
+                    "ImplementationSpecific".to_string(),
+                )
+            }
+            Some(prefix) => (prefix, "Prefix".to_string()),
+            None => ("/".to_string(), "Prefix".to_string()),
+        };
+
+        if let Some(auth_url) = &config.auth_url {
+            annotations.insert(
+                "nginx.ingress.kubernetes.io/auth-url".to_string(),
+                auth_url.clone(),
+            );
+        }
+
         Ingress {
             metadata: ObjectMeta {
                 name: Some(name.clone()),
-                annotations: Some(constants.ingress_annotations.clone()),
+                annotations: Some(annotations),
                 ..Default::default()
             },
             spec: Some(IngressSpec {
-                ingress_class_name: Some(constants.ingress_class_name.clone()),
+                ingress_class_name: Some(config.ingress_class(constants)),
+                tls,
                 rules: Some(vec![IngressRule {
-                    host: Some(self.external_host(config, constants)),
+                    host: Some(host),
                     http: Some(HTTPIngressRuleValue {
                         paths: vec![HTTPIngressPath {
-                            path: Some("/".to_string()),
-                            path_type: "Prefix".to_string(),
+                            path: Some(path),
+                            path_type,
                             backend: IngressBackend {
                                 service: Some(IngressServiceBackend {
-                                    name: name.clone(),
+                                    name: backend_name,
                                     port: Some(ServiceBackendPort {
-                                        number: Some(constants.port),
+                                        number: Some(backend_port),
                                         ..Default::default()
                                     }),
                                 }),
@@ -514,4 +1762,383 @@ impl HydraDoomNode {
             ..Default::default()
         }
     }
+
+    pub fn http_route(&self, config: &Config, constants: &K8sConstants) -> HTTPRoute {
+        let name = self.internal_name();
+        let (backend_name, backend_port) = self.external_backend(config, constants);
+
+        HTTPRoute {
+            metadata: ObjectMeta {
+                name: Some(name),
+                annotations: Some(self.external_dns_annotations(config, constants)),
+                ..Default::default()
+            },
+            spec: HTTPRouteSpec {
+                parent_refs: Some(vec![ParentReference {
+                    name: config.gateway_name.clone().unwrap_or_default(),
+                    namespace: config.gateway_namespace.clone(),
+                    section_name: config.gateway_section_name.clone(),
+                }]),
+                hostnames: Some(vec![self.external_host(config, constants)]),
+                rules: Some(vec![HTTPRouteRule {
+                    matches: Some(vec![HTTPRouteMatch {
+                        path: Some(HTTPPathMatch {
+                            type_: "PathPrefix".to_string(),
+                            value: self.external_path(config).unwrap_or("/".to_string()),
+                        }),
+                    }]),
+                    filters: self.rewrites_path(config).then(|| {
+                        vec![HTTPRouteFilter {
+                            type_: "URLRewrite".to_string(),
+                            url_rewrite: Some(HTTPURLRewriteFilter {
+                                path: Some(HTTPPathModifier {
+                                    type_: "ReplacePrefixMatch".to_string(),
+                                    replace_prefix_match: Some("/".to_string()),
+                                }),
+                            }),
+                        }]
+                    }),
+                    backend_refs: Some(vec![HTTPBackendRef {
+                        name: backend_name,
+                        port: Some(backend_port),
+                    }]),
+                    // Websocket sessions are long-lived, so keep the route from
+                    // cutting them off after the gateway's default timeout.
+                    timeouts: Some(HTTPRouteTimeouts {
+                        request: Some(config.route_timeout.clone()),
+                        backend_request: Some(config.route_timeout.clone()),
+                    }),
+                }]),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node(name: &str) -> HydraDoomNode {
+        serde_json::from_value(json!({
+            "apiVersion": "hydra.doom/v1alpha1",
+            "kind": "HydraDoomNode",
+            "metadata": { "name": name, "namespace": "doom" },
+            "spec": { "seedInput": "", "commitInputs": [] },
+        }))
+        .unwrap()
+    }
+
+    /// Config with only the routing settings, read from `env`.
+    fn routing_config(env: &[(&str, &str)]) -> Config {
+        let env: BTreeMap<_, _> = env.iter().copied().collect();
+        let (config, problems) =
+            Config::read_env_from(&|var| env.get(var).map(|value| value.to_string()));
+        assert_eq!(problems, Vec::<String>::new());
+        config
+    }
+
+    fn ingress_rule(ingress: &Ingress) -> (String, String, String) {
+        let rule = &ingress.spec.as_ref().unwrap().rules.as_ref().unwrap()[0];
+        let path = &rule.http.as_ref().unwrap().paths[0];
+        (
+            rule.host.clone().unwrap(),
+            path.path.clone().unwrap(),
+            path.path_type.clone(),
+        )
+    }
+
+    #[test]
+    fn renders_ingress_routing() {
+        for (name, template, host, path, path_type) in [
+            ("node", None, "node.hydra.example.com", "/", "Prefix"),
+            (
+                "node",
+                Some("/{namespace}/{name}"),
+                "node.hydra.example.com",
+                r"/doom/node(/|$)(.*)",
+                "ImplementationSpecific",
+            ),
+            // Node names may contain dots, and templates anything at all.
+            (
+                "node.v1",
+                Some("/play+(now)/{name}"),
+                "node.v1.hydra.example.com",
+                r"/play\+\(now\)/node\.v1(/|$)(.*)",
+                "ImplementationSpecific",
+            ),
+        ] {
+            let mut env = vec![
+                ("EXTERNAL_DOMAIN", "hydra.example.com"),
+                ("EXTERNAL_PORT", "80"),
+            ];
+            if let Some(template) = template {
+                env.push(("EXTERNAL_PATH_TEMPLATE", template));
+            }
+            let config = routing_config(&env);
+            let ingress = node(name).ingress(&config, &K8sConstants::default());
+
+            assert_eq!(
+                ingress_rule(&ingress),
+                (host.to_string(), path.to_string(), path_type.to_string()),
+                "{:?}",
+                template
+            );
+            let annotations = ingress.metadata.annotations.unwrap();
+            assert_eq!(
+                annotations
+                    .get("nginx.ingress.kubernetes.io/rewrite-target")
+                    .map(String::as_str),
+                template.map(|_| "/$2"),
+            );
+        }
+    }
+
+    #[test]
+    fn renders_host_templates() {
+        let config = routing_config(&[
+            ("EXTERNAL_DOMAIN", "hydra.example.com"),
+            ("EXTERNAL_PORT", "443"),
+            ("EXTERNAL_HOST_TEMPLATE", "{name}-{namespace}.{domain}"),
+        ]);
+        let node = node("node");
+        assert_eq!(
+            node.external_host(&config, &K8sConstants::default()),
+            "node-doom.hydra.example.com"
+        );
+        assert!(node.serves(
+            "node-doom.hydra.example.com",
+            "/",
+            &config,
+            &K8sConstants::default()
+        ));
+    }
+
+    #[test]
+    fn renders_ingress_tls_modes() {
+        for (mode, extra, secret, issuer) in [
+            ("none", None, None, None),
+            ("upstream", None, None, None),
+            (
+                "wildcard",
+                Some(("TLS_SECRET", "wildcard-tls")),
+                Some("wildcard-tls"),
+                None,
+            ),
+            (
+                "cert-manager",
+                Some(("CERT_MANAGER_ISSUER", "letsencrypt")),
+                Some("hydra-doom-node-node-tls"),
+                Some(("cert-manager.io/cluster-issuer", "letsencrypt")),
+            ),
+        ] {
+            let mut env = vec![
+                ("EXTERNAL_DOMAIN", "hydra.example.com"),
+                ("EXTERNAL_PORT", "80"),
+                ("TLS_MODE", mode),
+            ];
+            env.extend(extra);
+            let config = routing_config(&env);
+            let ingress = node("node").ingress(&config, &K8sConstants::default());
+
+            let tls = ingress.spec.as_ref().unwrap().tls.clone();
+            assert_eq!(
+                tls.as_ref().map(|tls| tls[0].secret_name.clone().unwrap()),
+                secret.map(str::to_string),
+                "{}",
+                mode
+            );
+            if let Some(tls) = tls {
+                assert_eq!(
+                    tls[0].hosts,
+                    Some(vec!["node.hydra.example.com".to_string()])
+                );
+            }
+            let annotations = ingress.metadata.annotations.unwrap();
+            if let Some((key, value)) = issuer {
+                assert_eq!(annotations.get(key).map(String::as_str), Some(value));
+            }
+            assert!(
+                !annotations.contains_key("cert-manager.io/issuer"),
+                "{}",
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn renders_auth_url_annotation() {
+        for auth_url in [
+            None,
+            Some("http://hydra-doom-auth.hydra-doom.svc:8081/auth"),
+        ] {
+            let mut env = vec![
+                ("EXTERNAL_DOMAIN", "hydra.example.com"),
+                ("EXTERNAL_PORT", "80"),
+            ];
+            env.extend(auth_url.map(|url| ("AUTH_URL", url)));
+            let config = routing_config(&env);
+            let annotations = node("node")
+                .ingress(&config, &K8sConstants::default())
+                .metadata
+                .annotations
+                .unwrap();
+            assert_eq!(
+                annotations
+                    .get("nginx.ingress.kubernetes.io/auth-url")
+                    .map(String::as_str),
+                auth_url
+            );
+        }
+    }
+
+    #[test]
+    fn renders_http_route_routing() {
+        for (template, path, rewrites) in [
+            (None, "/", false),
+            (Some("/{namespace}/{name}"), "/doom/node", true),
+            // Gateway path matches are literal, so nothing is escaped.
+            (Some("/play+/{name}"), "/play+/node", true),
+        ] {
+            let mut env = vec![
+                ("EXTERNAL_DOMAIN", "hydra.example.com"),
+                ("EXTERNAL_PORT", "80"),
+                ("EXPOSURE_MODE", "gateway"),
+                ("GATEWAY_NAME", "hydra"),
+            ];
+            env.extend(template.map(|template| ("EXTERNAL_PATH_TEMPLATE", template)));
+            let config = routing_config(&env);
+            let route = node("node").http_route(&config, &K8sConstants::default());
+
+            assert_eq!(
+                route.spec.hostnames,
+                Some(vec!["node.hydra.example.com".to_string()])
+            );
+            let rule = &route.spec.rules.unwrap()[0];
+            let path_match = rule.matches.as_ref().unwrap()[0].path.clone().unwrap();
+            assert_eq!(
+                (path_match.type_.as_str(), path_match.value.as_str()),
+                ("PathPrefix", path)
+            );
+            assert_eq!(rule.filters.is_some(), rewrites, "{:?}", template);
+            assert_eq!(route.spec.parent_refs.unwrap()[0].name, "hydra");
+        }
+    }
+
+    #[test]
+    fn fargate_label_is_opt_in() {
+        let mut node = node("node");
+        assert_eq!(node.pod_labels().get("run-on"), None);
+
+        node.spec.scheduling = Some(Scheduling {
+            profile: Some(SchedulingProfile::Fargate),
+            ..Default::default()
+        });
+        assert_eq!(
+            node.pod_labels().get("run-on").map(String::as_str),
+            Some("fargate")
+        );
+        // Not part of the selector, so the profile can change in place.
+        assert_eq!(node.internal_labels().get("run-on"), None);
+    }
+
+    #[test]
+    fn cardano_keys_outlive_their_node() {
+        let mut node = node("node");
+        node.metadata.uid = Some("uid".to_string());
+        node.spec.cardano_keys = Some(CardanoKeyMode::PerNode);
+        let config = routing_config(&[]);
+
+        let secret = node.cardano_keys_secret(&KeyPair::generate_payment(), "addr_test1", &config);
+        assert_eq!(secret.metadata.owner_references, None);
+        assert_eq!(
+            secret.labels().get(CARDANO_KEYS_LABEL).map(String::as_str),
+            Some("node")
+        );
+    }
+
+    fn quantities(requirements: &ResourceRequirements) -> (String, String) {
+        let memory =
+            |map: &Option<BTreeMap<String, Quantity>>| map.as_ref().unwrap()["memory"].0.clone();
+        (memory(&requirements.requests), memory(&requirements.limits))
+    }
+
+    #[test]
+    fn parses_quantities() {
+        assert_eq!(parse_quantity("2").unwrap(), 2.0);
+        assert_eq!(parse_quantity("500m").unwrap(), 0.5);
+        assert_eq!(parse_quantity("1.5").unwrap(), 1.5);
+        assert_eq!(parse_quantity("4Gi").unwrap(), 4.0 * 1024f64.powi(3));
+        assert_eq!(parse_quantity("128Mi").unwrap(), 128.0 * 1024f64.powi(2));
+        assert_eq!(parse_quantity("1k").unwrap(), 1e3);
+        assert_eq!(parse_quantity("2G").unwrap(), 2e9);
+        assert_eq!(parse_quantity("1e3").unwrap(), 1e3);
+        assert_eq!(parse_quantity("1E-3").unwrap(), 1e-3);
+    }
+
+    #[test]
+    fn rejects_invalid_quantities() {
+        for quantity in ["", "Gi", "4GB", "4gi", "one", "1e", "1.2.3"] {
+            assert!(parse_quantity(quantity).is_err(), "{}", quantity);
+        }
+    }
+
+    #[test]
+    fn request_above_preset_limit_raises_limit() {
+        let resources = Resources {
+            requests: Some(ResourcesInner {
+                memory: Some("8Gi".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let requirements = resources.requirements(ResourcePreset::Large).unwrap();
+        assert_eq!(
+            quantities(&requirements),
+            ("8Gi".to_string(), "8Gi".to_string())
+        );
+    }
+
+    #[test]
+    fn limit_below_preset_request_lowers_request() {
+        let resources = Resources {
+            limits: Some(ResourcesInner {
+                memory: Some("2Gi".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let requirements = resources.requirements(ResourcePreset::Large).unwrap();
+        assert_eq!(
+            quantities(&requirements),
+            ("2Gi".to_string(), "2Gi".to_string())
+        );
+    }
+
+    #[test]
+    fn explicit_request_above_explicit_limit_fails() {
+        let resources = Resources {
+            requests: Some(ResourcesInner {
+                memory: Some("8Gi".to_string()),
+                ..Default::default()
+            }),
+            limits: Some(ResourcesInner {
+                memory: Some("4Gi".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(resources.requirements(ResourcePreset::Large).is_err());
+    }
+
+    #[test]
+    fn preset_applies_without_explicit_quantities() {
+        let requirements = Resources::default()
+            .requirements(ResourcePreset::Small)
+            .unwrap();
+        assert_eq!(
+            quantities(&requirements),
+            ("128Mi".to_string(), "256Mi".to_string())
+        );
+    }
 }
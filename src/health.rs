@@ -0,0 +1,136 @@
+//! Health and metrics endpoints of the operator.
+//!
+//! `/healthz` answers as long as the process runs, `/readyz` once the
+//! controller has listed all nodes, and `/metrics` reports reconcile counters
+//! and nodes per state in the Prometheus text format.
+use kube::runtime::reflector::Store;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{info, warn};
+
+use crate::{
+    custom_resource::HydraDoomNode,
+    http::{read_request_head, respond},
+};
+
+pub struct HealthServer {
+    store: Store<HydraDoomNode>,
+    ready: AtomicBool,
+    reconciles: AtomicU64,
+    reconcile_errors: AtomicU64,
+}
+
+impl HealthServer {
+    pub fn new(store: Store<HydraDoomNode>) -> Self {
+        Self {
+            store,
+            ready: AtomicBool::new(false),
+            reconciles: AtomicU64::new(0),
+            reconcile_errors: AtomicU64::new(0),
+        }
+    }
+
+    pub fn record_reconcile(&self, success: bool) {
+        self.reconciles.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.reconcile_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Marks the operator ready once the controller's store is filled.
+    pub async fn watch_readiness(&self) {
+        if self.store.wait_until_ready().await.is_ok() {
+            self.ready.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub async fn run(self: Arc<Self>, addr: SocketAddr) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Health endpoint listening on {}.", addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                if let Err(err) = server.handle(stream).await {
+                    warn!(
+                        err = err.to_string(),
+                        "Health request from {} failed.", peer
+                    );
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, mut stream: TcpStream) -> anyhow::Result<()> {
+        let head = read_request_head(&mut stream).await?;
+        let path = head.path.split('?').next().unwrap_or_default();
+
+        match path {
+            "/healthz" => respond(&mut stream, 200, "OK", "text/plain", "ok").await,
+            "/readyz" if self.ready.load(Ordering::Relaxed) => {
+                respond(&mut stream, 200, "OK", "text/plain", "ok").await
+            }
+            "/readyz" => {
+                respond(
+                    &mut stream,
+                    503,
+                    "Service Unavailable",
+                    "text/plain",
+                    "not ready",
+                )
+                .await
+            }
+            "/metrics" => {
+                respond(
+                    &mut stream,
+                    200,
+                    "OK",
+                    "text/plain; version=0.0.4",
+                    &self.render_metrics(),
+                )
+                .await
+            }
+            _ => respond(&mut stream, 404, "Not Found", "text/plain", "").await,
+        }
+    }
+
+    fn render_metrics(&self) -> String {
+        let mut nodes: BTreeMap<String, u64> = BTreeMap::new();
+        for crd in self.store.state() {
+            let state = crd
+                .status
+                .as_ref()
+                .map(|status| status.state.clone())
+                .unwrap_or("Unknown".to_string());
+            *nodes.entry(state).or_default() += 1;
+        }
+
+        let mut body = String::new();
+        let _ = writeln!(body, "# TYPE doom_patrol_reconciles_total counter");
+        let _ = writeln!(
+            body,
+            "doom_patrol_reconciles_total {}",
+            self.reconciles.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(body, "# TYPE doom_patrol_reconcile_errors_total counter");
+        let _ = writeln!(
+            body,
+            "doom_patrol_reconcile_errors_total {}",
+            self.reconcile_errors.load(Ordering::Relaxed)
+        );
+        let _ = writeln!(body, "# TYPE doom_patrol_nodes gauge");
+        for (state, count) in nodes {
+            let _ = writeln!(body, "doom_patrol_nodes{{state=\"{}\"}} {}", state, count);
+        }
+        body
+    }
+}
@@ -0,0 +1,245 @@
+//! Minimal HTTP/1.1 helpers for the small servers embedded in this crate.
+//! They mostly only need to look at the request head before either answering
+//! directly or handing the raw connection over to a proxy; the admission
+//! webhook also reads a small body.
+use anyhow::bail;
+use std::collections::BTreeMap;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+const MAX_HEAD_SIZE: usize = 16 * 1024;
+const MAX_BODY_SIZE: usize = 4 * 1024 * 1024;
+
+pub struct RequestHead {
+    pub method: String,
+    pub path: String,
+    /// Header names are lowercased.
+    pub headers: BTreeMap<String, String>,
+    /// Every byte read from the connection so far, head included, so that the
+    /// request can be replayed verbatim to an upstream.
+    pub raw: Vec<u8>,
+}
+impl RequestHead {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).map(|value| value.as_str())
+    }
+
+    /// The raw request with the path in the request line replaced.
+    pub fn with_path(&self, path: &str) -> Vec<u8> {
+        let line_end = self
+            .raw
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .unwrap_or(self.raw.len());
+        let request_line = String::from_utf8_lossy(&self.raw[..line_end]);
+        let version = request_line.rsplit(' ').next().unwrap_or("HTTP/1.1");
+
+        let mut raw = format!("{} {} {}", self.method, path, version).into_bytes();
+        raw.extend_from_slice(&self.raw[line_end..]);
+        raw
+    }
+
+    /// Host header without the port.
+    pub fn host(&self) -> Option<&str> {
+        self.header("host")
+            .map(|host| host.split(':').next().unwrap_or(host))
+    }
+}
+
+/// Value of a query string parameter in a request URI.
+pub fn query_param(uri: &str, name: &str) -> Option<String> {
+    let (_, query) = uri.split_once('?')?;
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        (key == name).then(|| value.to_string())
+    })
+}
+
+pub async fn read_request_head<S>(stream: &mut S) -> anyhow::Result<RequestHead>
+where
+    S: AsyncRead + Unpin,
+{
+    let mut raw = Vec::with_capacity(1024);
+    let mut buf = [0u8; 1024];
+
+    let head_end = loop {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            bail!("Connection closed before the request head was complete");
+        }
+        raw.extend_from_slice(&buf[..read]);
+        if let Some(pos) = raw.windows(4).position(|window| window == b"\r\n\r\n") {
+            break pos;
+        }
+        if raw.len() > MAX_HEAD_SIZE {
+            bail!("Request head too large");
+        }
+    };
+
+    let head = String::from_utf8_lossy(&raw[..head_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        bail!("Malformed request line");
+    };
+
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+        .collect();
+
+    Ok(RequestHead {
+        method: method.to_string(),
+        path: path.to_string(),
+        headers,
+        raw,
+    })
+}
+
+/// Reads the request body announced by the `Content-Length` header.
+pub async fn read_body<S>(stream: &mut S, head: &RequestHead) -> anyhow::Result<Vec<u8>>
+where
+    S: AsyncRead + Unpin,
+{
+    let length: usize = head
+        .header("content-length")
+        .unwrap_or("0")
+        .parse()
+        .map_err(|_| anyhow::anyhow!("Invalid Content-Length"))?;
+    if length > MAX_BODY_SIZE {
+        bail!("Request body too large");
+    }
+
+    let head_end = head
+        .raw
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map(|pos| pos + 4)
+        .unwrap_or(head.raw.len());
+    let mut body = head.raw[head_end..].to_vec();
+    if body.len() < length {
+        let mut rest = vec![0u8; length - body.len()];
+        stream.read_exact(&mut rest).await?;
+        body.extend_from_slice(&rest);
+    }
+    body.truncate(length);
+    Ok(body)
+}
+
+pub async fn respond<S>(
+    stream: &mut S,
+    status: u16,
+    reason: &str,
+    content_type: &str,
+    body: &str,
+) -> anyhow::Result<()>
+where
+    S: AsyncWrite + Unpin,
+{
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        content_type,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::duplex;
+
+    #[tokio::test]
+    async fn parses_request_head() {
+        let raw = b"GET /ws?token=abc HTTP/1.1\r\nHost: node.example.com:443\r\nUpgrade: websocket\r\n\r\n";
+        let head = read_request_head(&mut &raw[..]).await.unwrap();
+
+        assert_eq!(head.method, "GET");
+        assert_eq!(head.path, "/ws?token=abc");
+        assert_eq!(head.header("upgrade"), Some("websocket"));
+        assert_eq!(head.header("Upgrade"), None);
+        assert_eq!(head.host(), Some("node.example.com"));
+        assert_eq!(head.raw, raw);
+    }
+
+    #[tokio::test]
+    async fn reads_head_split_across_reads() {
+        let (mut client, mut server) = duplex(64);
+        tokio::spawn(async move {
+            client.write_all(b"GET / HTTP/1.1\r\nHo").await.unwrap();
+            client.write_all(b"st: a\r\n\r").await.unwrap();
+            client.write_all(b"\n").await.unwrap();
+        });
+
+        let head = read_request_head(&mut server).await.unwrap();
+        assert_eq!(head.host(), Some("a"));
+    }
+
+    #[tokio::test]
+    async fn rejects_incomplete_and_malformed_heads() {
+        let truncated = b"GET / HTTP/1.1\r\nHost: a\r\n";
+        assert!(read_request_head(&mut &truncated[..]).await.is_err());
+
+        let malformed = b"GET\r\n\r\n";
+        assert!(read_request_head(&mut &malformed[..]).await.is_err());
+
+        let huge = [
+            b"GET / HTTP/1.1\r\nX: ".as_slice(),
+            &[b'a'; MAX_HEAD_SIZE + 1],
+        ]
+        .concat();
+        assert!(read_request_head(&mut &huge[..]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn replaces_path() {
+        let raw = b"GET /node/ws HTTP/1.1\r\nHost: a\r\n\r\n";
+        let head = read_request_head(&mut &raw[..]).await.unwrap();
+
+        assert_eq!(
+            head.with_path("/ws"),
+            b"GET /ws HTTP/1.1\r\nHost: a\r\n\r\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_body() {
+        let body = vec![b'x'; 3000];
+        let raw = [
+            format!("POST / HTTP/1.1\r\nContent-Length: {}\r\n\r\n", body.len()).as_bytes(),
+            &body,
+        ]
+        .concat();
+        let mut stream = &raw[..];
+
+        let head = read_request_head(&mut stream).await.unwrap();
+        assert_eq!(read_body(&mut stream, &head).await.unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_content_length() {
+        let raw = b"POST / HTTP/1.1\r\nContent-Length: nope\r\n\r\n";
+        let mut stream = &raw[..];
+        let head = read_request_head(&mut stream).await.unwrap();
+        assert!(read_body(&mut stream, &head).await.is_err());
+
+        let raw = b"POST / HTTP/1.1\r\nContent-Length: 10\r\n\r\nshort";
+        let mut stream = &raw[..];
+        let head = read_request_head(&mut stream).await.unwrap();
+        assert!(read_body(&mut stream, &head).await.is_err());
+    }
+
+    #[test]
+    fn finds_query_params() {
+        assert_eq!(
+            query_param("/ws?a=1&token=abc", "token"),
+            Some("abc".into())
+        );
+        assert_eq!(query_param("/ws?flag", "flag"), Some("".into()));
+        assert_eq!(query_param("/ws", "token"), None);
+    }
+}
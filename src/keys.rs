@@ -0,0 +1,152 @@
+//! Key generation for nodes, in the cardano-cli text envelope format that
+//! hydra-node reads.
+use anyhow::{anyhow, bail};
+use bech32::{Bech32, Hrp};
+use blake2::{digest::consts::U28, Blake2b, Digest};
+use ed25519_dalek::SigningKey;
+use serde_json::json;
+
+pub static HYDRA_SIGNING_KEY: &str = "hydra.sk";
+pub static HYDRA_VERIFICATION_KEY: &str = "hydra.vk";
+pub static PAYMENT_SIGNING_KEY: &str = "payment.sk";
+pub static PAYMENT_VERIFICATION_KEY: &str = "payment.vk";
+pub static PAYMENT_ADDRESS: &str = "payment.addr";
+
+/// CBOR prefix of a 32 byte bytestring, which is how keys are wrapped inside
+/// a text envelope's `cborHex`.
+const CBOR_BYTES_32: &str = "5820";
+
+pub struct KeyPair {
+    pub signing_key: String,
+    pub verification_key: String,
+    pub verification_key_bytes: [u8; 32],
+}
+impl KeyPair {
+    pub fn generate(signing_type: &str, verification_type: &str) -> Self {
+        let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+        let verification_key = signing_key.verifying_key();
+
+        Self {
+            signing_key: text_envelope(signing_type, signing_key.as_bytes()),
+            verification_key: text_envelope(verification_type, verification_key.as_bytes()),
+            verification_key_bytes: verification_key.to_bytes(),
+        }
+    }
+
+    pub fn generate_hydra() -> Self {
+        Self::generate("HydraSigningKey_ed25519", "HydraVerificationKey_ed25519")
+    }
+
+    pub fn generate_payment() -> Self {
+        Self::generate(
+            "PaymentSigningKeyShelley_ed25519",
+            "PaymentVerificationKeyShelley_ed25519",
+        )
+    }
+}
+
+pub fn text_envelope(type_: &str, key: &[u8]) -> String {
+    serde_json::to_string_pretty(&json!({
+        "type": type_,
+        "description": "",
+        "cborHex": format!("{}{}", CBOR_BYTES_32, hex::encode(key)),
+    }))
+    .unwrap()
+}
+
+/// Raw key bytes out of a text envelope.
+pub fn read_text_envelope(envelope: &[u8]) -> anyhow::Result<Vec<u8>> {
+    let envelope: serde_json::Value = serde_json::from_slice(envelope)?;
+    let cbor_hex = envelope["cborHex"]
+        .as_str()
+        .ok_or(anyhow!("Text envelope without cborHex"))?;
+    let Some(key_hex) = cbor_hex.strip_prefix(CBOR_BYTES_32) else {
+        bail!("Unexpected key encoding in text envelope");
+    };
+    Ok(hex::decode(key_hex)?)
+}
+
+/// Blake2b-224 hash of a verification key, as used for key hashes on Cardano.
+pub fn key_hash(verification_key: &[u8]) -> [u8; 28] {
+    Blake2b::<U28>::digest(verification_key).into()
+}
+
+/// Bech32 enterprise address (payment key only, no staking part) for a
+/// payment verification key. Network id 1 is mainnet, anything else a testnet;
+/// addresses only tell the two apart.
+pub fn enterprise_address(verification_key: &[u8], network_id: u8) -> anyhow::Result<String> {
+    let mainnet = network_id == 1;
+    let mut address = vec![0b0110_0000 | u8::from(mainnet)];
+    address.extend_from_slice(&key_hash(verification_key));
+
+    let hrp = Hrp::parse(if mainnet { "addr" } else { "addr_test" })?;
+    Ok(bech32::encode::<Bech32>(hrp, &address)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bech32_bytes(value: &str) -> Vec<u8> {
+        bech32::decode(value).unwrap().1
+    }
+
+    #[test]
+    fn wraps_keys_in_text_envelopes() {
+        // RFC 8032, test 1.
+        let secret =
+            hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap();
+        let public = SigningKey::from_bytes(&secret.clone().try_into().unwrap()).verifying_key();
+        assert_eq!(
+            hex::encode(public.as_bytes()),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+
+        let envelope = text_envelope("PaymentSigningKeyShelley_ed25519", &secret);
+        let value: serde_json::Value = serde_json::from_str(&envelope).unwrap();
+        assert_eq!(value["type"], "PaymentSigningKeyShelley_ed25519");
+        assert_eq!(
+            value["cborHex"],
+            "58209d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60"
+        );
+        assert_eq!(read_text_envelope(envelope.as_bytes()).unwrap(), secret);
+    }
+
+    #[test]
+    fn rejects_unexpected_envelopes() {
+        assert!(read_text_envelope(br#"{"type": "x"}"#).is_err());
+        assert!(read_text_envelope(br#"{"cborHex": "5840aa"}"#).is_err());
+    }
+
+    #[test]
+    fn derives_enterprise_addresses() {
+        // CIP-19 test vectors.
+        let vk = bech32_bytes("addr_vk1w0l2sr2zgfm26ztc6nl9xy8ghsk5sh6ldwemlpmp9xylzy4dtf7st80zhd");
+        assert_eq!(
+            hex::encode(key_hash(&vk)),
+            "9493315cd92eb5d8c4304e67b7e16ae36d61d34502694657811a2c8e"
+        );
+        assert_eq!(
+            enterprise_address(&vk, 1).unwrap(),
+            "addr1vx2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzers66hrl8"
+        );
+        for network_id in [0, 2] {
+            assert_eq!(
+                enterprise_address(&vk, network_id).unwrap(),
+                "addr_test1vz2fxv2umyhttkxyxp8x0dlpdt3k6cwng5pxj3jhsydzerspjrlsz"
+            );
+        }
+    }
+
+    #[test]
+    fn generated_keys_round_trip() {
+        let pair = KeyPair::generate_payment();
+        let signing = read_text_envelope(pair.signing_key.as_bytes()).unwrap();
+        let verification = read_text_envelope(pair.verification_key.as_bytes()).unwrap();
+
+        let signing = SigningKey::from_bytes(&signing.try_into().unwrap());
+        assert_eq!(signing.verifying_key().as_bytes().as_slice(), verification);
+        assert_eq!(verification, pair.verification_key_bytes);
+    }
+}
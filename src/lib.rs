@@ -1,5 +1,15 @@
+pub mod activator;
+pub mod auth;
 pub mod config;
 pub mod controller;
 pub mod custom_resource;
+pub mod gateway;
+pub mod health;
+pub mod http;
+pub mod keys;
+pub mod overrides;
+pub mod pool;
+pub mod reload;
+pub mod webhook;
 
 pub use custom_resource::HydraDoomNode;
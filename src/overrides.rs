@@ -0,0 +1,435 @@
+//! User supplied overrides for the objects generated for a node.
+//!
+//! Each override is a strategic-merge style fragment, a list of JSON patch
+//! operations, or both. The fragment is merged first and the operations are
+//! applied to the result. Both run after the builders, so almost anything the
+//! operator generates can be changed. The result has to parse as the original
+//! kind again and keep the fields the operator relies on to find its objects.
+use anyhow::{anyhow, bail};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{config::Config, controller::K8sConstants, custom_resource::HydraDoomNode};
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Overrides {
+    pub deployment: Option<ObjectOverride>,
+    pub service: Option<ObjectOverride>,
+    pub ingress: Option<ObjectOverride>,
+    pub configmap: Option<ObjectOverride>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectOverride {
+    /// Merged into the generated object. Objects are merged key by key and
+    /// `null` removes a key. Lists with a Kubernetes merge key are merged item
+    /// by item: containers, env, volumes and imagePullSecrets by `name`,
+    /// volumeMounts by `mountPath`, container ports by `containerPort`,
+    /// service ports by `port` and hostAliases by `ip`. An item with
+    /// `$patch: delete` removes its match. Other lists are replaced.
+    #[serde(default)]
+    #[schemars(schema_with = "free_form_object")]
+    pub merge: Option<Value>,
+    /// RFC 6902 operations applied after `merge`.
+    pub json_patch: Option<Vec<JsonPatchOperation>>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonPatchOperation {
+    pub op: JsonPatchOp,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "free_form_value")]
+    pub value: Option<Value>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonPatchOp {
+    Add,
+    Remove,
+    Replace,
+    Move,
+    Copy,
+    Test,
+}
+
+fn free_form_object(_: &mut SchemaGenerator) -> Schema {
+    serde_json::from_value(json!({
+        "type": "object",
+        "nullable": true,
+        "x-kubernetes-preserve-unknown-fields": true,
+    }))
+    .unwrap()
+}
+
+fn free_form_value(_: &mut SchemaGenerator) -> Schema {
+    serde_json::from_value(json!({
+        "nullable": true,
+        "x-kubernetes-preserve-unknown-fields": true,
+    }))
+    .unwrap()
+}
+
+/// Fields the operator finds its objects by, which overrides must leave as
+/// generated.
+const IMMUTABLE_FIELDS: [&str; 4] = [
+    "/metadata/name",
+    "/metadata/namespace",
+    "/metadata/ownerReferences",
+    "/spec/selector",
+];
+
+/// Applies `overrides` to `object`, failing when an operation does not apply,
+/// the result is no longer a valid `T` or one of `IMMUTABLE_FIELDS` changed.
+pub fn apply_overrides<T>(object: T, overrides: Option<&ObjectOverride>) -> anyhow::Result<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let Some(overrides) = overrides else {
+        return Ok(object);
+    };
+
+    let original = serde_json::to_value(&object)?;
+    let mut value = original.clone();
+    if let Some(fragment) = &overrides.merge {
+        merge(&mut value, fragment, "")?;
+    }
+    if let Some(operations) = &overrides.json_patch {
+        let patch: json_patch::Patch = serde_json::from_value(serde_json::to_value(operations)?)
+            .map_err(|err| anyhow!("Invalid JSON patch: {}", err))?;
+        json_patch::patch(&mut value, &patch)
+            .map_err(|err| anyhow!("Failed to apply JSON patch: {}", err))?;
+    }
+    for field in IMMUTABLE_FIELDS {
+        if original.pointer(field) != value.pointer(field) {
+            bail!("Overrides must not change {}", field);
+        }
+    }
+    serde_json::from_value(value).map_err(|err| anyhow!("Overridden object is invalid: {}", err))
+}
+
+fn merge(target: &mut Value, fragment: &Value, field: &str) -> anyhow::Result<()> {
+    match (target, fragment) {
+        (Value::Object(target), Value::Object(fragment)) => {
+            for (key, value) in fragment {
+                if value.is_null() {
+                    target.remove(key);
+                } else {
+                    merge(target.entry(key.clone()).or_insert(Value::Null), value, key)?;
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(fragment)) => {
+            match merge_key(field, target, fragment) {
+                Some(key) => merge_list(target, fragment, field, key)?,
+                None => *target = fragment.clone(),
+            }
+        }
+        (target, fragment) => *target = fragment.clone(),
+    }
+    Ok(())
+}
+
+/// Merge key of the list in `field`, as in the Kubernetes API. `ports` is
+/// keyed by `containerPort` in containers and by `port` in services.
+fn merge_key(field: &str, target: &[Value], fragment: &[Value]) -> Option<&'static str> {
+    match field {
+        "containers" | "initContainers" | "env" | "volumes" | "imagePullSecrets" => Some("name"),
+        "volumeMounts" => Some("mountPath"),
+        "hostAliases" => Some("ip"),
+        "ports" => {
+            let in_container = target
+                .iter()
+                .chain(fragment)
+                .any(|item| item.get("containerPort").is_some());
+            Some(if in_container {
+                "containerPort"
+            } else {
+                "port"
+            })
+        }
+        _ => None,
+    }
+}
+
+fn merge_list(
+    target: &mut Vec<Value>,
+    fragment: &[Value],
+    field: &str,
+    key: &str,
+) -> anyhow::Result<()> {
+    for item in fragment {
+        let Some(id) = item.get(key).filter(|id| !id.is_null()) else {
+            bail!("Item of {} without its merge key {}", field, key);
+        };
+        let position = target
+            .iter()
+            .position(|existing| existing.get(key) == Some(id));
+        if item.get("$patch").and_then(Value::as_str) == Some("delete") {
+            if let Some(position) = position {
+                target.remove(position);
+            }
+            continue;
+        }
+        match position {
+            Some(position) => merge(&mut target[position], item, field)?,
+            None => target.push(item.clone()),
+        }
+    }
+    Ok(())
+}
+
+impl HydraDoomNode {
+    pub fn deployment_overrides(&self) -> Option<&ObjectOverride> {
+        self.spec.overrides.as_ref()?.deployment.as_ref()
+    }
+
+    pub fn service_overrides(&self) -> Option<&ObjectOverride> {
+        self.spec.overrides.as_ref()?.service.as_ref()
+    }
+
+    pub fn ingress_overrides(&self) -> Option<&ObjectOverride> {
+        self.spec.overrides.as_ref()?.ingress.as_ref()
+    }
+
+    pub fn configmap_overrides(&self) -> Option<&ObjectOverride> {
+        self.spec.overrides.as_ref()?.configmap.as_ref()
+    }
+
+    /// Builds every object that can be overridden and applies its overrides,
+    /// returning one message per object that does not survive them.
+    pub fn validate_overrides(&self, config: &Config, constants: &K8sConstants) -> Vec<String> {
+        let results = [
+            (
+                "deployment",
+                self.deployment(config, constants)
+                    .and_then(|deployment| apply_overrides(deployment, self.deployment_overrides()))
+                    .err(),
+            ),
+            (
+                "service",
+                apply_overrides(self.service(config, constants), self.service_overrides()).err(),
+            ),
+            (
+                "ingress",
+                apply_overrides(self.ingress(config, constants), self.ingress_overrides()).err(),
+            ),
+            (
+                "configmap",
+                apply_overrides(
+                    self.configmap(config, constants),
+                    self.configmap_overrides(),
+                )
+                .err(),
+            ),
+        ];
+
+        results
+            .into_iter()
+            .filter_map(|(kind, err)| err.map(|err| format!("Invalid {} override: {}", kind, err)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::{apps::v1::Deployment, core::v1::Service};
+
+    fn merged(target: Value, fragment: Value) -> Value {
+        let mut target = target;
+        merge(&mut target, &fragment, "").unwrap();
+        target
+    }
+
+    fn deployment() -> Deployment {
+        serde_json::from_value(json!({
+            "metadata": { "name": "hydra-doom-node-a" },
+            "spec": {
+                "selector": { "matchLabels": { "hydra-doom-node-id": "a" } },
+                "template": {
+                    "spec": {
+                        "containers": [
+                            {
+                                "name": "main",
+                                "image": "hydra-node",
+                                "env": [{ "name": "A", "value": "1" }],
+                                "ports": [{ "name": "api", "containerPort": 4001 }],
+                            },
+                            { "name": "sidecar", "image": "sidecar" },
+                        ],
+                    },
+                },
+            },
+        }))
+        .unwrap()
+    }
+
+    fn merge_override(fragment: Value) -> ObjectOverride {
+        ObjectOverride {
+            merge: Some(fragment),
+            json_patch: None,
+        }
+    }
+
+    #[test]
+    fn merges_objects_and_removes_null_keys() {
+        assert_eq!(
+            merged(
+                json!({ "a": 1, "b": { "c": 2, "d": 3 } }),
+                json!({ "b": { "c": 4, "d": null }, "e": 5 }),
+            ),
+            json!({ "a": 1, "b": { "c": 4 }, "e": 5 })
+        );
+    }
+
+    #[test]
+    fn merges_lists_by_their_merge_keys() {
+        let target = json!({
+            "containers": [{
+                "name": "main",
+                "env": [{ "name": "A", "value": "1" }, { "name": "B", "value": "2" }],
+                "volumeMounts": [{ "name": "data", "mountPath": "/var/data" }],
+                "ports": [{ "name": "api", "containerPort": 4001 }],
+            }],
+            "hostAliases": [{ "ip": "10.0.0.1", "hostnames": ["a"] }],
+        });
+        let fragment = json!({
+            "containers": [{
+                "name": "main",
+                "env": [{ "name": "B", "value": "3" }, { "name": "C", "value": "4" }],
+                "volumeMounts": [{ "name": "other", "mountPath": "/var/data" }],
+                "ports": [{ "name": "peer", "containerPort": 5001 }],
+            }],
+            "hostAliases": [{ "ip": "10.0.0.1", "hostnames": ["b"] }],
+        });
+        assert_eq!(
+            merged(target, fragment),
+            json!({
+                "containers": [{
+                    "name": "main",
+                    "env": [
+                        { "name": "A", "value": "1" },
+                        { "name": "B", "value": "3" },
+                        { "name": "C", "value": "4" },
+                    ],
+                    "volumeMounts": [{ "name": "other", "mountPath": "/var/data" }],
+                    "ports": [
+                        { "name": "api", "containerPort": 4001 },
+                        { "name": "peer", "containerPort": 5001 },
+                    ],
+                }],
+                "hostAliases": [{ "ip": "10.0.0.1", "hostnames": ["b"] }],
+            })
+        );
+    }
+
+    #[test]
+    fn merges_service_ports_by_port() {
+        assert_eq!(
+            merged(
+                json!({ "ports": [{ "name": "api", "port": 4001 }] }),
+                json!({ "ports": [{ "port": 4001, "nodePort": 30001 }] }),
+            ),
+            json!({ "ports": [{ "name": "api", "port": 4001, "nodePort": 30001 }] })
+        );
+    }
+
+    #[test]
+    fn deletes_items_and_replaces_unkeyed_lists() {
+        assert_eq!(
+            merged(
+                json!({
+                    "env": [{ "name": "A" }, { "name": "B" }],
+                    "args": ["--a", "--b"],
+                }),
+                json!({
+                    "env": [{ "name": "A", "$patch": "delete" }],
+                    "args": ["--c"],
+                }),
+            ),
+            json!({ "env": [{ "name": "B" }], "args": ["--c"] })
+        );
+    }
+
+    #[test]
+    fn rejects_items_without_merge_key() {
+        let mut target = json!({ "env": [{ "name": "A" }] });
+        assert!(merge(&mut target, &json!({ "env": [{ "value": "1" }] }), "").is_err());
+    }
+
+    #[test]
+    fn applies_merge_then_json_patch() {
+        let overrides = ObjectOverride {
+            merge: Some(json!({
+                "spec": { "template": { "spec": { "containers": [
+                    { "name": "sidecar", "image": "sidecar:v2" },
+                ] } } },
+            })),
+            json_patch: Some(vec![JsonPatchOperation {
+                op: JsonPatchOp::Add,
+                path: "/spec/template/spec/containers/0/args".to_string(),
+                from: None,
+                value: Some(json!(["--verbose"])),
+            }]),
+        };
+        let deployment = apply_overrides(deployment(), Some(&overrides)).unwrap();
+        let containers = deployment.spec.unwrap().template.spec.unwrap().containers;
+        assert_eq!(containers.len(), 2);
+        assert_eq!(containers[0].args, Some(vec!["--verbose".to_string()]));
+        assert_eq!(containers[0].env.as_ref().unwrap().len(), 1);
+        assert_eq!(containers[1].image.as_deref(), Some("sidecar:v2"));
+    }
+
+    #[test]
+    fn rejects_results_of_the_wrong_shape() {
+        let overrides = merge_override(json!({ "spec": { "replicas": "two" } }));
+        assert!(apply_overrides(deployment(), Some(&overrides)).is_err());
+
+        let overrides = ObjectOverride {
+            merge: None,
+            json_patch: Some(vec![JsonPatchOperation {
+                op: JsonPatchOp::Remove,
+                path: "/spec/missing".to_string(),
+                from: None,
+                value: None,
+            }]),
+        };
+        assert!(apply_overrides(deployment(), Some(&overrides)).is_err());
+    }
+
+    #[test]
+    fn rejects_changes_to_immutable_fields() {
+        for fragment in [
+            json!({ "metadata": { "name": "other" } }),
+            json!({ "metadata": { "namespace": "other" } }),
+            json!({ "metadata": { "ownerReferences": [{
+                "apiVersion": "v1", "kind": "Pod", "name": "a", "uid": "1",
+            }] } }),
+            json!({ "spec": { "selector": { "matchLabels": { "other": "a" } } } }),
+        ] {
+            let overrides = merge_override(fragment.clone());
+            let err = apply_overrides(deployment(), Some(&overrides)).unwrap_err();
+            assert!(
+                err.to_string().starts_with("Overrides must not change"),
+                "{}",
+                fragment
+            );
+        }
+
+        let service: Service = serde_json::from_value(json!({
+            "metadata": { "name": "hydra-doom-node-a" },
+            "spec": { "selector": { "hydra-doom-node-id": "a" } },
+        }))
+        .unwrap();
+        let overrides = merge_override(json!({ "spec": { "selector": null } }));
+        assert!(apply_overrides(service, Some(&overrides)).is_err());
+    }
+}
@@ -0,0 +1,630 @@
+//! Warm node pools and claims.
+//!
+//! A `HydraDoomNodePool` keeps `replicas` unclaimed nodes built from its
+//! template. A `HydraDoomNodeClaim` binds one of the pool's nodes that already
+//! reached the pool's target state by labelling it, and reports the node's
+//! external URL in its status. Claimed nodes are replaced by fresh ones and
+//! deleted once the claim goes away or its TTL expires.
+//!
+//! Nodes carry a hash of the template they were built from. When the
+//! template changes, unclaimed nodes built from an older one are replaced:
+//! ready ones only once enough current nodes are ready to take their place.
+use blake2::{digest::consts::U28, Blake2b, Digest};
+use k8s_openapi::chrono::{DateTime, Utc};
+use kube::{
+    api::{DeleteParams, ListParams, ObjectMeta, Patch, PatchParams, PostParams},
+    Api, Resource, ResourceExt,
+};
+use serde_json::json;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use tracing::{error, info, warn};
+
+use crate::{
+    controller::K8sContext,
+    custom_resource::{
+        HydraDoomNode, HydraDoomNodeClaim, HydraDoomNodeClaimStatus, HydraDoomNodePool,
+        HydraDoomNodePoolStatus,
+    },
+};
+
+pub static POOL_LABEL: &str = "hydra.doom/pool";
+pub static CLAIM_LABEL: &str = "hydra.doom/claim";
+pub static TEMPLATE_HASH_ANNOTATION: &str = "hydra.doom/template-hash";
+
+pub enum ClaimPhase {
+    Pending,
+    Bound,
+    Released,
+}
+impl From<ClaimPhase> for String {
+    fn from(val: ClaimPhase) -> Self {
+        match val {
+            ClaimPhase::Pending => "Pending".to_string(),
+            ClaimPhase::Bound => "Bound".to_string(),
+            ClaimPhase::Released => "Released".to_string(),
+        }
+    }
+}
+
+/// Hash of the pool's node template, stamped on every node created from it.
+fn template_hash(pool: &HydraDoomNodePool) -> String {
+    hex::encode(Blake2b::<U28>::digest(
+        serde_json::to_vec(&pool.spec.template).unwrap_or_default(),
+    ))
+}
+
+fn has_template(node: &HydraDoomNode, hash: &str) -> bool {
+    node.annotations()
+        .get(TEMPLATE_HASH_ANNOTATION)
+        .is_some_and(|value| value == hash)
+}
+
+/// Whether `claim` has been bound for at least `ttl` seconds.
+fn claim_expired(claim: &HydraDoomNodeClaim, ttl: Option<u64>, now: DateTime<Utc>) -> bool {
+    let Some(ttl) = ttl else {
+        return false;
+    };
+    claim
+        .status
+        .as_ref()
+        .and_then(|status| status.bound_at.as_deref())
+        .and_then(|at| DateTime::parse_from_rfc3339(at).ok())
+        .is_some_and(|at| (now - at.with_timezone(&Utc)).num_seconds() >= ttl as i64)
+}
+
+/// A pool's nodes, sorted by what has to happen to them.
+#[derive(Default)]
+struct SortedNodes<'a> {
+    claimed: Vec<HydraDoomNode>,
+    unclaimed: Vec<HydraDoomNode>,
+    /// Nodes to delete, along with the claim to mark released if it still
+    /// exists and has no other node.
+    released: Vec<(HydraDoomNode, Option<&'a HydraDoomNodeClaim>)>,
+}
+
+/// Sorts the pool's live nodes. Nodes whose claim is gone or expired are
+/// released. A claim keeps at most one node, preferably the one its status
+/// names; extra ones are left over from binds whose claim status was never
+/// written.
+fn sort_nodes<'a>(
+    mut nodes: Vec<HydraDoomNode>,
+    claims: &[&'a HydraDoomNodeClaim],
+    ttl: Option<u64>,
+    now: DateTime<Utc>,
+) -> SortedNodes<'a> {
+    nodes.sort_by_key(|node| {
+        !claims.iter().any(|claim| {
+            claim
+                .status
+                .as_ref()
+                .is_some_and(|status| status.node == Some(node.name_any()))
+        })
+    });
+
+    let mut sorted = SortedNodes::default();
+    for node in nodes
+        .into_iter()
+        .filter(|node| node.metadata.deletion_timestamp.is_none())
+    {
+        let Some(claim_name) = node.labels().get(CLAIM_LABEL).cloned() else {
+            sorted.unclaimed.push(node);
+            continue;
+        };
+        let claim = claims
+            .iter()
+            .find(|claim| claim.name_any() == claim_name)
+            .copied();
+        let duplicate = sorted
+            .claimed
+            .iter()
+            .any(|other| other.labels().get(CLAIM_LABEL) == Some(&claim_name));
+
+        match claim {
+            Some(_) if duplicate => sorted.released.push((node, None)),
+            Some(claim) if claim_expired(claim, ttl, now) => {
+                sorted.released.push((node, Some(claim)))
+            }
+            Some(_) => sorted.claimed.push(node),
+            None => sorted.released.push((node, None)),
+        }
+    }
+    sorted
+}
+
+/// Position of the unclaimed node to bind a claim to: a ready one built from
+/// the current template if there is one, otherwise any ready one.
+fn pick_ready(
+    unclaimed: &[HydraDoomNode],
+    is_ready: impl Fn(&HydraDoomNode) -> bool,
+    hash: &str,
+) -> Option<usize> {
+    unclaimed
+        .iter()
+        .position(|node| is_ready(node) && has_template(node, hash))
+        .or_else(|| unclaimed.iter().position(&is_ready))
+}
+
+/// Names of the unclaimed nodes to delete so that the pool converges on
+/// `replicas` nodes built from the current template. Not-ready nodes go
+/// first; ready nodes from an older template stay until enough current ones
+/// are ready to replace them.
+fn surplus(
+    unclaimed: &[HydraDoomNode],
+    replicas: usize,
+    is_ready: impl Fn(&HydraDoomNode) -> bool,
+    hash: &str,
+) -> Vec<String> {
+    let (mut current, stale): (Vec<_>, Vec<_>) =
+        unclaimed.iter().partition(|node| has_template(node, hash));
+    current.sort_by_key(|node| !is_ready(node));
+
+    let mut surplus: Vec<_> = current.iter().skip(replicas).copied().collect();
+    let current_ready = current
+        .iter()
+        .take(replicas)
+        .filter(|node| is_ready(node))
+        .count();
+    let (stale_ready, stale_warming): (Vec<_>, Vec<_>) =
+        stale.into_iter().partition(|node| is_ready(node));
+    surplus.extend(stale_warming);
+    surplus.extend(
+        stale_ready
+            .into_iter()
+            .skip(replicas.saturating_sub(current_ready)),
+    );
+    surplus.into_iter().map(|node| node.name_any()).collect()
+}
+
+/// Status of a claim bound to `node`.
+fn bound_status(node: &HydraDoomNode) -> HydraDoomNodeClaimStatus {
+    HydraDoomNodeClaimStatus {
+        phase: ClaimPhase::Bound.into(),
+        node: Some(node.name_any()),
+        external_url: node
+            .status
+            .as_ref()
+            .map(|status| status.external_url.clone()),
+        bound_at: Some(Utc::now().to_rfc3339()),
+    }
+}
+
+impl K8sContext {
+    async fn reconcile_pool(
+        &self,
+        pool: &HydraDoomNodePool,
+        claims: &[HydraDoomNodeClaim],
+    ) -> anyhow::Result<()> {
+        let pool_name = pool.name_any();
+        let namespace = pool.namespace().unwrap();
+        let nodes_api: Api<HydraDoomNode> = Api::namespaced(self.client.clone(), &namespace);
+        let claims_api: Api<HydraDoomNodeClaim> = Api::namespaced(self.client.clone(), &namespace);
+
+        let nodes = nodes_api
+            .list(&ListParams::default().labels(&format!("{}={}", POOL_LABEL, pool_name)))
+            .await?
+            .items;
+        let hash = template_hash(pool);
+        let target_state: String = (&pool.spec.target_state.clone().unwrap_or_default()).into();
+        // With external-dns the URL is only published once it resolves, and a
+        // claim must not be handed an empty one.
+        let external_dns = self.config().external_dns;
+        let is_ready = |node: &HydraDoomNode| {
+            node.metadata.deletion_timestamp.is_none()
+                && node.status.as_ref().is_some_and(|status| {
+                    status.state == target_state
+                        && (!external_dns || status.dns_ready == Some(true))
+                })
+        };
+
+        let claims: Vec<_> = claims
+            .iter()
+            .filter(|claim| claim.spec.pool == pool_name)
+            .collect();
+
+        let SortedNodes {
+            mut claimed,
+            mut unclaimed,
+            released,
+        } = sort_nodes(nodes, &claims, pool.spec.claim_ttl_seconds, Utc::now());
+        for (node, claim) in released {
+            info!(
+                "Releasing node {} from pool {}.",
+                node.name_any(),
+                pool_name
+            );
+            if let Err(err) = nodes_api
+                .delete(&node.name_any(), &DeleteParams::default())
+                .await
+            {
+                warn!(
+                    err = err.to_string(),
+                    "Failed to release node {}.",
+                    node.name_any()
+                );
+                continue;
+            }
+            if let Some(claim) = claim {
+                self.patch_claim_status(
+                    &claims_api,
+                    claim,
+                    HydraDoomNodeClaimStatus {
+                        phase: ClaimPhase::Released.into(),
+                        ..claim.status.clone().unwrap_or_default()
+                    },
+                )
+                .await;
+            }
+        }
+        let node_of = |claim: &HydraDoomNodeClaim, claimed: &[HydraDoomNode]| {
+            claimed
+                .iter()
+                .find(|node| node.labels().get(CLAIM_LABEL) == Some(&claim.name_any()))
+                .cloned()
+        };
+
+        // Bind pending claims to ready, unclaimed nodes. A node that already
+        // carries the claim's label is its binding, even if the claim status
+        // did not make it.
+        for claim in claims.iter().filter(|claim| {
+            claim
+                .status
+                .as_ref()
+                .map(|status| status.phase == String::from(ClaimPhase::Pending))
+                .unwrap_or(true)
+        }) {
+            if let Some(node) = node_of(claim, &claimed) {
+                info!(
+                    "Recording binding of node {} to claim {}.",
+                    node.name_any(),
+                    claim.name_any()
+                );
+                self.patch_claim_status(&claims_api, claim, bound_status(&node))
+                    .await;
+                continue;
+            }
+
+            let Some(position) = pick_ready(&unclaimed, is_ready, &hash) else {
+                self.patch_claim_status(
+                    &claims_api,
+                    claim,
+                    HydraDoomNodeClaimStatus {
+                        phase: ClaimPhase::Pending.into(),
+                        ..Default::default()
+                    },
+                )
+                .await;
+                continue;
+            };
+            let mut node = unclaimed.remove(position);
+
+            // Including the resource version makes the bind fail if anything
+            // else touched the node since we listed it, so two claims can never
+            // end up on the same node.
+            let bind = nodes_api
+                .patch(
+                    &node.name_any(),
+                    &PatchParams::default(),
+                    &Patch::Merge(json!({
+                        "metadata": {
+                            "resourceVersion": node.resource_version(),
+                            "labels": { CLAIM_LABEL: claim.name_any() }
+                        }
+                    })),
+                )
+                .await;
+            if let Err(err) = bind {
+                warn!(
+                    err = err.to_string(),
+                    "Failed to bind node {} to claim {}.",
+                    node.name_any(),
+                    claim.name_any()
+                );
+                continue;
+            }
+
+            info!(
+                "Bound node {} to claim {}.",
+                node.name_any(),
+                claim.name_any()
+            );
+            node.labels_mut()
+                .insert(CLAIM_LABEL.to_string(), claim.name_any());
+            self.patch_claim_status(&claims_api, claim, bound_status(&node))
+                .await;
+            claimed.push(node);
+        }
+
+        // Claims whose node disappeared under them are released as well.
+        for claim in claims.iter().filter(|claim| {
+            claim.status.as_ref().is_some_and(|status| {
+                status.phase == String::from(ClaimPhase::Bound)
+                    && node_of(claim, &claimed).is_none()
+            })
+        }) {
+            self.patch_claim_status(
+                &claims_api,
+                claim,
+                HydraDoomNodeClaimStatus {
+                    phase: ClaimPhase::Released.into(),
+                    ..claim.status.clone().unwrap_or_default()
+                },
+            )
+            .await;
+        }
+
+        // Drop what the pool no longer needs, then top up the warm nodes.
+        for name in surplus(&unclaimed, pool.spec.replicas as usize, is_ready, &hash) {
+            info!("Removing node {} from pool {}.", name, pool_name);
+            match nodes_api.delete(&name, &DeleteParams::default()).await {
+                Ok(_) => unclaimed.retain(|node| node.name_any() != name),
+                Err(err) => warn!(err = err.to_string(), "Failed to remove node {}.", name),
+            }
+        }
+        let current = unclaimed
+            .iter()
+            .filter(|node| has_template(node, &hash))
+            .count();
+        let mut created = 0;
+        for _ in current..pool.spec.replicas as usize {
+            let node = HydraDoomNode {
+                metadata: ObjectMeta {
+                    generate_name: Some(format!("{}-", pool_name)),
+                    namespace: Some(namespace.clone()),
+                    labels: Some(BTreeMap::from([(
+                        POOL_LABEL.to_string(),
+                        pool_name.clone(),
+                    )])),
+                    annotations: Some(BTreeMap::from([(
+                        TEMPLATE_HASH_ANNOTATION.to_string(),
+                        hash.clone(),
+                    )])),
+                    owner_references: pool.controller_owner_ref(&()).map(|owner| vec![owner]),
+                    ..Default::default()
+                },
+                spec: pool.spec.template.clone(),
+                status: None,
+            };
+            match nodes_api.create(&PostParams::default(), &node).await {
+                Ok(node) => {
+                    info!("Created node {} for pool {}.", node.name_any(), pool_name);
+                    created += 1;
+                }
+                Err(err) => error!(
+                    err = err.to_string(),
+                    "Failed to create node for pool {}.", pool_name
+                ),
+            }
+        }
+
+        let status = HydraDoomNodePoolStatus {
+            ready: unclaimed.iter().filter(|node| is_ready(node)).count() as u32,
+            warming: (unclaimed.iter().filter(|node| !is_ready(node)).count() + created) as u32,
+            claimed: claimed.len() as u32,
+        };
+        let pools_api: Api<HydraDoomNodePool> = Api::namespaced(self.client.clone(), &namespace);
+        pools_api
+            .patch_status(
+                &pool_name,
+                &PatchParams::default(),
+                &Patch::Merge(json!({ "status": status })),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn patch_claim_status(
+        &self,
+        api: &Api<HydraDoomNodeClaim>,
+        claim: &HydraDoomNodeClaim,
+        status: HydraDoomNodeClaimStatus,
+    ) {
+        if let Err(err) = api
+            .patch_status(
+                &claim.name_any(),
+                &PatchParams::default(),
+                &Patch::Merge(json!({ "status": status })),
+            )
+            .await
+        {
+            warn!(
+                err = err.to_string(),
+                "Failed to update status for claim {}.",
+                claim.name_any()
+            );
+        }
+    }
+
+    async fn reconcile_pools(&self) -> anyhow::Result<()> {
+        let pools_api: Api<HydraDoomNodePool> = Api::default_namespaced(self.client.clone());
+        let claims_api: Api<HydraDoomNodeClaim> = Api::default_namespaced(self.client.clone());
+
+        let pools = pools_api.list(&ListParams::default()).await?;
+        let claims = claims_api.list(&ListParams::default()).await?.items;
+
+        for pool in &pools {
+            if let Err(err) = self.reconcile_pool(pool, &claims).await {
+                error!(
+                    err = err.to_string(),
+                    "Failed to reconcile pool {}.",
+                    pool.name_any()
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub async fn reconcile_pools(context: Arc<K8sContext>) -> anyhow::Result<()> {
+    info!("Running pool reconciler loop.");
+
+    loop {
+        // A failed pass, e.g. a list that timed out, is retried on the next.
+        if let Err(err) = context.reconcile_pools().await {
+            error!(err = err.to_string(), "Failed to reconcile pools.");
+        }
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_resource::HydraDoomNodeClaimSpec;
+    use k8s_openapi::chrono::TimeDelta;
+
+    const HASH: &str = "current";
+
+    fn node(name: &str, claim: Option<&str>, hash: &str, state: &str) -> HydraDoomNode {
+        serde_json::from_value(json!({
+            "apiVersion": "hydra.doom/v1alpha1",
+            "kind": "HydraDoomNode",
+            "metadata": {
+                "name": name,
+                "namespace": "hydra-doom",
+                "labels": match claim {
+                    Some(claim) => json!({ POOL_LABEL: "pool", CLAIM_LABEL: claim }),
+                    None => json!({ POOL_LABEL: "pool" }),
+                },
+                "annotations": { TEMPLATE_HASH_ANNOTATION: hash },
+            },
+            "spec": { "seedInput": "", "commitInputs": [] },
+            "status": {
+                "localUrl": "",
+                "externalUrl": "",
+                "state": state,
+                "transactions": 0,
+            },
+        }))
+        .unwrap()
+    }
+
+    fn claim(
+        name: &str,
+        node: Option<&str>,
+        bound_at: Option<DateTime<Utc>>,
+    ) -> HydraDoomNodeClaim {
+        let mut claim = HydraDoomNodeClaim::new(
+            name,
+            HydraDoomNodeClaimSpec {
+                pool: "pool".to_string(),
+            },
+        );
+        claim.status = Some(HydraDoomNodeClaimStatus {
+            phase: ClaimPhase::Bound.into(),
+            node: node.map(str::to_string),
+            external_url: None,
+            bound_at: bound_at.map(|at| at.to_rfc3339()),
+        });
+        claim
+    }
+
+    fn names(nodes: &[HydraDoomNode]) -> Vec<String> {
+        nodes.iter().map(|node| node.name_any()).collect()
+    }
+
+    fn is_ready(node: &HydraDoomNode) -> bool {
+        node.status
+            .as_ref()
+            .is_some_and(|status| status.state == "Online")
+    }
+
+    #[test]
+    fn claims_expire_after_their_ttl() {
+        let now = Utc::now();
+        let bound = claim("claim", None, Some(now - TimeDelta::seconds(60)));
+        assert!(!claim_expired(&bound, None, now));
+        assert!(!claim_expired(&bound, Some(61), now));
+        assert!(claim_expired(&bound, Some(60), now));
+        assert!(!claim_expired(&claim("claim", None, None), Some(0), now));
+    }
+
+    #[test]
+    fn releases_nodes_of_missing_and_expired_claims() {
+        let now = Utc::now();
+        let active = claim("active", Some("a"), Some(now));
+        let expired = claim("expired", Some("b"), Some(now - TimeDelta::seconds(600)));
+        let nodes = vec![
+            node("a", Some("active"), HASH, "Online"),
+            node("b", Some("expired"), HASH, "Online"),
+            node("c", Some("gone"), HASH, "Online"),
+            node("d", None, HASH, "Online"),
+        ];
+
+        let sorted = sort_nodes(nodes, &[&active, &expired], Some(300), now);
+        assert_eq!(names(&sorted.claimed), vec!["a"]);
+        assert_eq!(names(&sorted.unclaimed), vec!["d"]);
+        let released: Vec<_> = sorted
+            .released
+            .iter()
+            .map(|(node, claim)| (node.name_any(), claim.map(|claim| claim.name_any())))
+            .collect();
+        assert_eq!(
+            released,
+            vec![
+                ("b".to_string(), Some("expired".to_string())),
+                ("c".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_the_node_a_claim_status_names() {
+        let now = Utc::now();
+        let bound = claim("claim", Some("b"), Some(now));
+        let nodes = vec![
+            node("a", Some("claim"), HASH, "Online"),
+            node("b", Some("claim"), HASH, "Online"),
+        ];
+
+        let sorted = sort_nodes(nodes, &[&bound], None, now);
+        assert_eq!(names(&sorted.claimed), vec!["b"]);
+        // The duplicate goes without touching the claim.
+        assert_eq!(sorted.released.len(), 1);
+        assert_eq!(sorted.released[0].0.name_any(), "a");
+        assert!(sorted.released[0].1.is_none());
+    }
+
+    #[test]
+    fn binds_current_ready_nodes_first() {
+        let unclaimed = vec![
+            node("warming", None, HASH, "Offline"),
+            node("stale", None, "old", "Online"),
+            node("current", None, HASH, "Online"),
+        ];
+        assert_eq!(pick_ready(&unclaimed, is_ready, HASH), Some(2));
+        assert_eq!(pick_ready(&unclaimed[..2], is_ready, HASH), Some(1));
+        assert_eq!(pick_ready(&unclaimed[..1], is_ready, HASH), None);
+    }
+
+    #[test]
+    fn removes_not_ready_surplus_first() {
+        let unclaimed = vec![
+            node("warming", None, HASH, "Offline"),
+            node("ready-1", None, HASH, "Online"),
+            node("ready-2", None, HASH, "Online"),
+        ];
+        assert_eq!(surplus(&unclaimed, 2, is_ready, HASH), vec!["warming"]);
+        assert_eq!(surplus(&unclaimed, 3, is_ready, HASH), Vec::<String>::new());
+    }
+
+    #[test]
+    fn replaces_stale_nodes_once_current_ones_are_ready() {
+        let unclaimed = vec![
+            node("stale-ready", None, "old", "Online"),
+            node("stale-warming", None, "old", "Offline"),
+            node("current-warming", None, HASH, "Offline"),
+        ];
+        assert_eq!(
+            surplus(&unclaimed, 1, is_ready, HASH),
+            vec!["stale-warming"]
+        );
+
+        let unclaimed = vec![
+            node("stale-ready", None, "old", "Online"),
+            node("current-ready", None, HASH, "Online"),
+        ];
+        assert_eq!(surplus(&unclaimed, 1, is_ready, HASH), vec!["stale-ready"]);
+    }
+}
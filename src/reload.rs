@@ -0,0 +1,301 @@
+//! Hot reload of the operator config from `HydraDoomConfig` resources.
+//!
+//! The `ClusterHydraDoomConfig` and the `HydraDoomConfig` in the operator's
+//! namespace named by `Config::config_resource` are watched; every valid
+//! combination of them is layered over the env config and handed to the
+//! running process. The operator then reconciles all nodes again. Invalid
+//! versions are reported through the `Valid` condition and leave the running
+//! config untouched.
+use futures::StreamExt;
+use k8s_openapi::{
+    apimachinery::pkg::apis::meta::v1::{Condition, Time},
+    chrono::Utc,
+};
+use kube::{
+    api::{Patch, PatchParams},
+    runtime::{
+        reflector::{self, ObjectRef},
+        watcher, WatchStreamExt,
+    },
+    Api, Client, Resource, ResourceExt,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::fmt::Debug;
+use tracing::{error, info, warn};
+
+use crate::{
+    config::Config,
+    custom_resource::{ClusterHydraDoomConfig, HydraDoomConfig, HydraDoomConfigStatus},
+};
+
+/// `config` with the cluster-wide resource and then the namespaced one
+/// applied.
+fn layered(
+    config: &Config,
+    cluster: Option<&ClusterHydraDoomConfig>,
+    namespaced: Option<&HydraDoomConfig>,
+) -> Config {
+    let config = match cluster {
+        Some(resource) => config.with_overrides(&resource.spec.settings),
+        None => config.clone(),
+    };
+    match namespaced {
+        Some(resource) => config.with_overrides(&resource.spec),
+        None => config,
+    }
+}
+
+/// Env config with the config resources applied, if there are any. For
+/// startup checks; long running processes use `watch_config`.
+pub async fn load_config(client: Client, config: Config) -> anyhow::Result<Config> {
+    let cluster = Api::<ClusterHydraDoomConfig>::all(client.clone())
+        .get_opt(&config.config_resource)
+        .await?;
+    let namespaced = Api::<HydraDoomConfig>::namespaced(client, &config.operator_namespace)
+        .get_opt(&config.config_resource)
+        .await?;
+    Ok(layered(&config, cluster.as_ref(), namespaced.as_ref()))
+}
+
+/// Watches the config resources, calling `apply` with the effective config
+/// whenever a new version of either one passes `validate`. With `report` set,
+/// their `Valid` conditions are updated too; only the operator does that.
+pub async fn watch_config(
+    client: Client,
+    base_config: Config,
+    validate: fn(&Config) -> Vec<String>,
+    report: bool,
+    apply: impl Fn(Config),
+) {
+    info!("Running config watcher loop.");
+
+    let name = base_config.config_resource.clone();
+    let watcher_config = watcher::Config::default().fields(&format!("metadata.name={}", name));
+    let cluster_api: Api<ClusterHydraDoomConfig> = Api::all(client.clone());
+    let namespace = &base_config.operator_namespace;
+    let namespaced_api: Api<HydraDoomConfig> = Api::namespaced(client.clone(), namespace);
+    let cluster_ref = ObjectRef::new(&name);
+    let namespaced_ref = ObjectRef::new(&name).within(namespace);
+
+    let (cluster_store, cluster_writer) = reflector::store();
+    let (namespaced_store, namespaced_writer) = reflector::store();
+    let cluster_events = reflector::reflector(
+        cluster_writer,
+        watcher(cluster_api.clone(), watcher_config.clone()),
+    )
+    .default_backoff()
+    .map(|event| (true, event.map(|event| relevant(&event))));
+    let namespaced_events = reflector::reflector(
+        namespaced_writer,
+        watcher(namespaced_api.clone(), watcher_config),
+    )
+    .default_backoff()
+    .map(|event| (false, event.map(|event| relevant(&event))));
+    let mut events = futures::stream::select(cluster_events, namespaced_events).boxed();
+
+    // Status updates come back as events too; only new generations matter.
+    let mut applied_versions = None;
+    let (mut cluster_synced, mut namespaced_synced) = (false, false);
+    while let Some((is_cluster, event)) = events.next().await {
+        match event {
+            Ok(Some(init_done)) => {
+                if is_cluster {
+                    cluster_synced |= init_done;
+                } else {
+                    namespaced_synced |= init_done;
+                }
+            }
+            Ok(None) => continue,
+            Err(err) => {
+                warn!(err = err.to_string(), "Config watcher error.");
+                continue;
+            }
+        }
+        // Until both have been listed a missing one cannot be told apart from
+        // one not seen yet.
+        if !cluster_synced || !namespaced_synced {
+            continue;
+        }
+
+        let cluster = cluster_store.get(&cluster_ref);
+        let namespaced = namespaced_store.get(&namespaced_ref);
+        let versions = (
+            cluster
+                .as_ref()
+                .map(|resource| (resource.uid(), resource.metadata.generation)),
+            namespaced
+                .as_ref()
+                .map(|resource| (resource.uid(), resource.metadata.generation)),
+        );
+        if applied_versions.as_ref() == Some(&versions) {
+            continue;
+        }
+        applied_versions = Some(versions);
+
+        let config = layered(&base_config, cluster.as_deref(), namespaced.as_deref());
+        let problems = validate(&config);
+
+        if report {
+            if let Some(resource) = &cluster {
+                patch_config_status(
+                    &cluster_api,
+                    resource.as_ref(),
+                    resource.status.as_ref(),
+                    &problems,
+                )
+                .await;
+            }
+            if let Some(resource) = &namespaced {
+                patch_config_status(
+                    &namespaced_api,
+                    resource.as_ref(),
+                    resource.status.as_ref(),
+                    &problems,
+                )
+                .await;
+            }
+        }
+
+        if problems.is_empty() {
+            info!("Applying operator config.");
+            apply(config);
+        } else {
+            error!("Ignoring invalid operator config: {}.", problems.join("; "));
+        }
+    }
+}
+
+/// Whether a watch event can change the effective config, and whether it
+/// completes the initial list. Events of the initial list itself are only
+/// in the store once it is complete.
+fn relevant<K>(event: &watcher::Event<K>) -> Option<bool> {
+    match event {
+        watcher::Event::Apply(_) | watcher::Event::Delete(_) => Some(false),
+        watcher::Event::InitDone => Some(true),
+        watcher::Event::Init | watcher::Event::InitApply(_) => None,
+    }
+}
+
+async fn patch_config_status<K>(
+    api: &Api<K>,
+    resource: &K,
+    previous: Option<&HydraDoomConfigStatus>,
+    problems: &[String],
+) where
+    K: Resource + Clone + DeserializeOwned + Debug,
+{
+    let (status, reason, message) = if problems.is_empty() {
+        ("True", "Valid", "Config applied".to_string())
+    } else {
+        ("False", "Invalid", problems.join("; "))
+    };
+
+    // Only move the transition time when the condition actually flips.
+    let previous =
+        previous.and_then(|status| status.conditions.iter().find(|c| c.type_ == "Valid"));
+    let last_transition_time = match previous {
+        Some(previous) if previous.status == status => previous.last_transition_time.clone(),
+        _ => Time(Utc::now()),
+    };
+
+    let generation = resource.meta().generation;
+    let status = HydraDoomConfigStatus {
+        observed_generation: generation,
+        conditions: vec![Condition {
+            type_: "Valid".to_string(),
+            status: status.to_string(),
+            reason: reason.to_string(),
+            message,
+            observed_generation: generation,
+            last_transition_time,
+        }],
+    };
+
+    if let Err(err) = api
+        .patch_status(
+            &resource.name_any(),
+            &PatchParams::default(),
+            &Patch::Merge(json!({ "status": status })),
+        )
+        .await
+    {
+        warn!(
+            err = err.to_string(),
+            "Failed to update status for config {}.",
+            resource.name_any()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::custom_resource::{ClusterHydraDoomConfigSpec, HydraDoomConfigSpec};
+
+    fn env_config() -> Config {
+        let (config, _) = Config::read_env_from(&|var| match var {
+            "EXTERNAL_DOMAIN" => Some("env.example.com".to_string()),
+            "EXTERNAL_PORT" => Some("80".to_string()),
+            "SECRET" => Some("env-secret".to_string()),
+            _ => None,
+        });
+        config
+    }
+
+    fn cluster(spec: HydraDoomConfigSpec) -> ClusterHydraDoomConfig {
+        ClusterHydraDoomConfig::new("hydra-doom", ClusterHydraDoomConfigSpec { settings: spec })
+    }
+
+    fn namespaced(spec: HydraDoomConfigSpec) -> HydraDoomConfig {
+        HydraDoomConfig::new("hydra-doom", spec)
+    }
+
+    #[test]
+    fn env_config_applies_without_resources() {
+        let config = layered(&env_config(), None, None);
+        assert_eq!(config.external_domain, "env.example.com");
+        assert_eq!(config.secret, "env-secret");
+    }
+
+    #[test]
+    fn namespaced_overrides_cluster_overrides_env() {
+        let cluster = cluster(HydraDoomConfigSpec {
+            external_domain: Some("cluster.example.com".to_string()),
+            external_port: Some("443".to_string()),
+            ..Default::default()
+        });
+        let namespaced = namespaced(HydraDoomConfigSpec {
+            external_domain: Some("namespace.example.com".to_string()),
+            ..Default::default()
+        });
+
+        let config = layered(&env_config(), Some(&cluster), Some(&namespaced));
+        assert_eq!(config.external_domain, "namespace.example.com");
+        assert_eq!(config.external_port, "443");
+        assert_eq!(config.secret, "env-secret");
+
+        let config = layered(&env_config(), Some(&cluster), None);
+        assert_eq!(config.external_domain, "cluster.example.com");
+
+        let config = layered(&env_config(), None, Some(&namespaced));
+        assert_eq!(config.external_domain, "namespace.example.com");
+        assert_eq!(config.external_port, "80");
+    }
+
+    #[test]
+    fn reads_the_namespaced_config_from_the_operator_namespace() {
+        let (config, _) = Config::read_env_from(&|var| match var {
+            "POD_NAMESPACE" => Some("doom-operator".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.operator_namespace, "doom-operator");
+
+        let (config, _) = Config::read_env_from(&|var| match var {
+            "OPERATOR_NAMESPACE" => Some("override".to_string()),
+            "POD_NAMESPACE" => Some("doom-operator".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.operator_namespace, "override");
+    }
+}
@@ -0,0 +1,205 @@
+//! Validating admission webhook for HydraDoomNode resources.
+//!
+//! Rejects nodes whose overrides do not apply to the generated objects, so a
+//! broken override is reported by `kubectl apply` instead of only showing up
+//! as a failing reconcile. Only changes to the overrides are checked: a node
+//! that is being deleted, or whose overrides are unchanged, is always
+//! admitted so that finalizers and status updates are never blocked.
+use anyhow::anyhow;
+use kube::core::{
+    admission::{AdmissionRequest, AdmissionResponse, AdmissionReview},
+    DynamicObject,
+};
+use std::{net::SocketAddr, sync::Arc};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_native_tls::{native_tls, TlsAcceptor, TlsStream};
+use tracing::{info, warn};
+
+use crate::{
+    controller::K8sContext,
+    custom_resource::HydraDoomNode,
+    http::{read_body, read_request_head, respond},
+};
+
+pub struct AdmissionServer {
+    context: Arc<K8sContext>,
+    acceptor: TlsAcceptor,
+}
+
+/// Loads the webhook's serving certificate. `cert_file` and `key_file` are
+/// PEM encoded, the key in PKCS #8.
+pub fn load_tls(cert_file: &str, key_file: &str) -> anyhow::Result<TlsAcceptor> {
+    let cert = std::fs::read(cert_file)
+        .map_err(|err| anyhow!("Failed to read webhook certificate {}: {}", cert_file, err))?;
+    let key = std::fs::read(key_file)
+        .map_err(|err| anyhow!("Failed to read webhook key {}: {}", key_file, err))?;
+    let identity = native_tls::Identity::from_pkcs8(&cert, &key)
+        .map_err(|err| anyhow!("Invalid webhook certificate or key: {}", err))?;
+    Ok(native_tls::TlsAcceptor::new(identity)?.into())
+}
+
+impl AdmissionServer {
+    pub fn new(context: Arc<K8sContext>, acceptor: TlsAcceptor) -> Self {
+        Self { context, acceptor }
+    }
+
+    pub async fn run(self: Arc<Self>, addr: SocketAddr) -> anyhow::Result<()> {
+        let listener = TcpListener::bind(addr).await?;
+        info!("Admission webhook listening on {}.", addr);
+
+        loop {
+            let (stream, peer) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                let result = match server.acceptor.accept(stream).await {
+                    Ok(stream) => server.handle(stream).await,
+                    Err(err) => Err(err.into()),
+                };
+                if let Err(err) = result {
+                    warn!(
+                        err = err.to_string(),
+                        "Admission request from {} failed.", peer
+                    );
+                }
+            });
+        }
+    }
+
+    async fn handle(&self, mut stream: TlsStream<TcpStream>) -> anyhow::Result<()> {
+        let head = read_request_head(&mut stream).await?;
+        if head.method != "POST" {
+            return respond(&mut stream, 405, "Method Not Allowed", "text/plain", "").await;
+        }
+        let body = read_body(&mut stream, &head).await?;
+
+        let review: AdmissionReview<DynamicObject> = match serde_json::from_slice(&body) {
+            Ok(review) => review,
+            Err(err) => {
+                return respond(
+                    &mut stream,
+                    400,
+                    "Bad Request",
+                    "text/plain",
+                    &err.to_string(),
+                )
+                .await
+            }
+        };
+        let request: Result<AdmissionRequest<DynamicObject>, _> = review.try_into();
+        let response = match request {
+            Ok(request) => self.review(&request),
+            Err(err) => AdmissionResponse::invalid(err.to_string()),
+        };
+
+        let body = serde_json::to_string(&response.into_review())?;
+        respond(&mut stream, 200, "OK", "application/json", &body).await
+    }
+
+    fn review(&self, request: &AdmissionRequest<DynamicObject>) -> AdmissionResponse {
+        let response = AdmissionResponse::from(request);
+        let Some(object) = &request.object else {
+            return response;
+        };
+        if !overrides_changed(request) {
+            return response;
+        }
+
+        // Parsed here rather than by the review so that a node that does not
+        // match the schema gets a readable message.
+        let crd: HydraDoomNode = match serde_json::to_value(object).and_then(serde_json::from_value)
+        {
+            Ok(crd) => crd,
+            Err(err) => return response.deny(format!("Invalid HydraDoomNode: {}", err)),
+        };
+        let problems = crd.validate_overrides(&self.context.config(), &self.context.constants);
+        if problems.is_empty() {
+            response
+        } else {
+            response.deny(problems.join("; "))
+        }
+    }
+}
+
+/// Whether the request needs its overrides validated. Deletions, and updates
+/// that leave `spec.overrides` as it was, are let through even if the stored
+/// overrides no longer apply, e.g. after an operator upgrade.
+fn overrides_changed(request: &AdmissionRequest<DynamicObject>) -> bool {
+    let Some(object) = &request.object else {
+        return false;
+    };
+    if object.metadata.deletion_timestamp.is_some() {
+        return false;
+    }
+    let overrides = |object: &DynamicObject| object.data.pointer("/spec/overrides").cloned();
+    match &request.old_object {
+        Some(old_object) => overrides(object) != overrides(old_object),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn node(overrides: Value, deleting: bool) -> Value {
+        let mut metadata = json!({ "name": "node-a", "namespace": "hydra-doom" });
+        if deleting {
+            metadata["deletionTimestamp"] = json!("2024-01-01T00:00:00Z");
+        }
+        json!({
+            "apiVersion": "hydra.doom/v1alpha1",
+            "kind": "HydraDoomNode",
+            "metadata": metadata,
+            "spec": { "overrides": overrides },
+        })
+    }
+
+    fn request(
+        operation: &str,
+        object: Value,
+        old_object: Option<Value>,
+    ) -> AdmissionRequest<DynamicObject> {
+        let review: AdmissionReview<DynamicObject> = serde_json::from_value(json!({
+            "apiVersion": "admission.k8s.io/v1",
+            "kind": "AdmissionReview",
+            "request": {
+                "uid": "1",
+                "kind": { "group": "hydra.doom", "version": "v1alpha1", "kind": "HydraDoomNode" },
+                "resource": { "group": "hydra.doom", "version": "v1alpha1", "resource": "hydradoomnodes" },
+                "operation": operation,
+                "userInfo": {},
+                "object": object,
+                "oldObject": old_object,
+            },
+        }))
+        .unwrap();
+        review.try_into().unwrap()
+    }
+
+    #[test]
+    fn only_changed_overrides_are_reviewed() {
+        let broken = json!({ "deployment": { "spec": { "replicas": "many" } } });
+        let fixed = json!({ "deployment": { "spec": { "replicas": 1 } } });
+
+        let create = request("CREATE", node(broken.clone(), false), None);
+        assert!(overrides_changed(&create));
+
+        let unchanged = request(
+            "UPDATE",
+            node(broken.clone(), false),
+            Some(node(broken.clone(), false)),
+        );
+        assert!(!overrides_changed(&unchanged));
+
+        let changed = request(
+            "UPDATE",
+            node(broken.clone(), false),
+            Some(node(fixed, false)),
+        );
+        assert!(overrides_changed(&changed));
+
+        let deleting = request("UPDATE", node(broken, true), Some(node(Value::Null, false)));
+        assert!(!overrides_changed(&deleting));
+    }
+}